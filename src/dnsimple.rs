@@ -1,3 +1,4 @@
+use crate::dnsimple::account_scope::account_id_of;
 use crate::dnsimple::accounts::Accounts;
 use crate::dnsimple::certificates::Certificates;
 use crate::dnsimple::contacts::Contacts;
@@ -16,32 +17,73 @@ use serde;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::collections::HashMap;
-use ureq::{Error, Request, Response};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+pub mod account_scope;
 pub mod accounts;
+#[cfg(feature = "async")]
+pub mod async_client;
+pub mod bulk;
+pub mod cassette;
 pub mod certificates;
+pub mod client_builder;
+pub mod config;
 pub mod contacts;
+pub mod credentials;
 pub mod domains;
 pub mod domains_collaborators;
 pub mod domains_dnssec;
 pub mod domains_email_forwards;
 pub mod domains_push;
 pub mod domains_signer_records;
+pub mod dry_run;
 pub mod identity;
+pub mod ids;
+mod instrumentation;
+pub mod middleware;
 pub mod oauth;
+pub mod paginator;
+pub mod rate_limiter;
+pub mod raw_request;
 pub mod registrar;
 pub mod registrar_auto_renewal;
 pub mod registrar_name_servers;
 pub mod registrar_whois_privacy;
+pub mod retry;
 pub mod services;
 pub mod templates;
+pub mod timestamps;
 pub mod tlds;
+pub mod transport;
 pub mod vanity_name_servers;
 pub mod webhooks;
 pub mod zones;
 pub mod zones_records;
 
+pub use crate::dnsimple::account_scope::AccountScope;
+#[cfg(feature = "async")]
+pub use crate::dnsimple::async_client::{new_async_client, AsyncClient};
+pub use crate::dnsimple::bulk::{BulkExecutor, BulkOutcome, BulkReport};
+pub use crate::dnsimple::cassette::{RecordingTransport, ReplayTransport};
+pub use crate::dnsimple::client_builder::ClientBuilder;
+pub use crate::dnsimple::credentials::{Credentials, CredentialsProvider};
+pub use crate::dnsimple::dry_run::{DryRun, PlannedOperation};
+pub use crate::dnsimple::ids::{
+    AccountId, CertificateId, CertificateRenewalId, CollaboratorId, ContactId,
+    DelegationSignerRecordId, DomainRef, DomainRegistrationId, DomainRenewalId, DomainTransferId,
+    EmailForwardId, PushId, ServiceRef, TemplateRecordId, TemplateRef, WebhookId, ZoneRecordId,
+};
+pub use crate::dnsimple::middleware::{Middleware, Next};
+pub use crate::dnsimple::paginator::Paginator;
+pub use crate::dnsimple::rate_limiter::{RateLimitMode, RateLimiter};
+pub use crate::dnsimple::raw_request::{Method, RawRequest};
+pub use crate::dnsimple::retry::RetryPolicy;
+pub use crate::dnsimple::timestamps::{Date, Timestamp};
+pub use crate::dnsimple::transport::{HttpRequest, HttpResponse, Transport, UreqTransport};
+
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const DEFAULT_USER_AGENT: &str = "dnsimple-rust/";
 
@@ -66,27 +108,119 @@ const DEFAULT_SANDBOX_URL: &str = "https://api.sandbox.dnsimple.com";
 /// let account = identity.account.unwrap();
 /// ```
 ///
+/// Cloning a client is cheap: the clones share the configuration of the client (transport,
+/// middlewares, credentials, rate limiter, dry-run...), and a client can be used from several
+/// threads.
+/// Changing the configuration of a clone (i.e. `set_base_url`) does not change the others.
+#[derive(Clone)]
 pub struct Client {
-    base_url: String,
-    user_agent: String,
-    auth_token: String,
-    pub _agent: ureq::Agent,
+    config: Arc<ClientConfig>,
+}
+
+/// The configuration of a `Client`, shared by its clones until one of them changes it
+#[derive(Clone)]
+pub(crate) struct ClientConfig {
+    pub(crate) base_url: String,
+    pub(crate) user_agent: String,
+    pub(crate) credentials: Arc<dyn CredentialsProvider>,
+    pub(crate) account_id: Option<AccountId>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) middlewares: Vec<Arc<dyn Middleware>>,
+    pub(crate) dry_run: Option<DryRun>,
 }
 
 /// Defines the Endpoint trait for the different API endpoints
 pub trait Endpoint {
-    type Output: DeserializeOwned;
+    type Output: DeserializeOwned + 'static;
+}
+
+/// Defines how the services talk to the DNSimple API.
+///
+/// The services (`zones()`, `registrar()`, `certificates()`...) are generic over the client
+/// sending the requests, so the blocking `Client` and the `AsyncClient` (behind the `async`
+/// feature) share the same endpoint definitions. With a `Client` every call returns a plain
+/// `Result`, with an `AsyncClient` it returns a future resolving to one.
+pub trait ApiClient {
+    /// The value returned by every call made through this client.
+    type Result<'a, T: 'a>
+    where
+        Self: 'a;
+
+    /// Sends a GET request to the DNSimple API
+    fn get<E: Endpoint>(
+        &self,
+        path: &str,
+        options: Option<RequestOptions>,
+    ) -> Self::Result<'_, DNSimpleResponse<E::Output>>;
+
+    /// Sends a POST request to the DNSimple API
+    fn post<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Self::Result<'_, DNSimpleResponse<E::Output>>;
+
+    /// Sends a POST request to the DNSimple API without any payload
+    fn empty_post(&self, path: &str) -> Self::Result<'_, DNSimpleEmptyResponse>;
+
+    /// Sends a PUT request to the DNSimple API
+    fn put<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Self::Result<'_, DNSimpleResponse<E::Output>>;
+
+    /// Sends a PUT request to the DNSimple API without any payload
+    fn empty_put(&self, path: &str) -> Self::Result<'_, DNSimpleEmptyResponse>;
+
+    /// Sends a PATCH request to the DNSimple API
+    fn patch<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Self::Result<'_, DNSimpleResponse<E::Output>>;
+
+    /// Sends a DELETE request to the DNSimple API
+    fn delete(&self, path: &str) -> Self::Result<'_, DNSimpleEmptyResponse>;
+
+    /// Sends a DELETE request to the DNSimple API returning a response containing a `DNSimpleResponse`
+    fn delete_with_response<E: Endpoint>(
+        &self,
+        path: &str,
+    ) -> Self::Result<'_, DNSimpleResponse<E::Output>>;
+
+    /// Sends an unauthenticated POST request to the DNSimple API and deserializes the whole body
+    /// (used by the OAuth flow, which does not wrap its response in `data`).
+    fn unauthenticated_post<T: DeserializeOwned + 'static>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Self::Result<'_, T>;
+
+    /// Returns the error without sending any request (used when the arguments of a call are
+    /// invalid, i.e. a domain name that cannot be normalized).
+    fn fail<T: 'static>(&self, error: DNSimpleError) -> Self::Result<'_, T>;
 }
 
+/// Normalizes the domain (or zone) given to a service method (see `DomainRef::normalize`),
+/// returning from the method with `ApiClient::fail` when it is not valid.
+macro_rules! normalize {
+    ($client:expr, $domain:expr) => {
+        match $crate::dnsimple::ids::DomainRef::normalize($domain.into()) {
+            Ok(domain) => domain,
+            Err(error) => return $client.fail(error),
+        }
+    };
+}
+pub(crate) use normalize;
+
 /// Represents the response from an API call
 #[derive(Debug)]
 pub struct DNSimpleResponse<T> {
-    /// The maximum number of requests you can perform per hour.
-    pub rate_limit: String,
-    /// The number of requests remaining in the current rate limit window.
-    pub rate_limit_remaining: String,
-    /// The time at which the current rate limit window in [Unix time](https://en.wikipedia.org/wiki/Unix_time) format.
-    pub rate_limit_reset: String,
+    /// The rate limit reported by the API (`None` if the headers are missing).
+    pub rate_limit: Option<RateLimit>,
     /// The HTTP Status Code
     pub status: u16,
     /// The object or a Vec<T> of objects (the type `T` will depend on the endpoint).
@@ -97,6 +231,43 @@ pub struct DNSimpleResponse<T> {
     pub body: Option<Value>,
 }
 
+/// The rate limit of the API, as reported by the `X-RateLimit-*` headers of a response
+///
+/// See [the API documentation](https://developer.dnsimple.com/v2/#rate-limiting).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RateLimit {
+    /// The maximum number of requests you can perform per hour.
+    pub limit: u32,
+    /// The number of requests remaining in the current rate limit window.
+    pub remaining: u32,
+    /// The time at which the current rate limit window resets.
+    pub reset: SystemTime,
+}
+
+impl RateLimit {
+    /// Parses the rate limit headers, returning `None` if any of them is missing or invalid.
+    pub(crate) fn from_headers(
+        limit: Option<&str>,
+        remaining: Option<&str>,
+        reset: Option<&str>,
+    ) -> Option<RateLimit> {
+        let reset: u64 = reset?.parse().ok()?;
+
+        Some(RateLimit {
+            limit: limit?.parse().ok()?,
+            remaining: remaining?.parse().ok()?,
+            reset: UNIX_EPOCH + Duration::from_secs(reset),
+        })
+    }
+
+    /// The time left until the current rate limit window resets (zero if it already did).
+    pub fn time_until_reset(&self) -> Duration {
+        self.reset
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
 /// Any API endpoint that returns a list of items requires pagination.
 /// By default we will return 30 records from any listing endpoint. If an API endpoint returns
 /// a list of items, then it will include a pagination object that contains pagination
@@ -114,6 +285,7 @@ pub struct Pagination {
 }
 
 /// When you can send some options into the request (i.e. for pagination).
+#[derive(Debug, Clone, Default)]
 pub struct RequestOptions {
     /// Filtering makes it possible to ask only for the exact subset of data that you you’re looking for.
     pub filters: Option<Filters>,
@@ -125,13 +297,10 @@ pub struct RequestOptions {
 
 /// Represents an empty response from the DNSimple API
 /// (_these type of responses happen when issuing DELETE commands for example_)
+#[derive(Debug)]
 pub struct DNSimpleEmptyResponse {
-    /// The maximum number of requests you can perform per hour.
-    pub rate_limit: String,
-    /// The number of requests remaining in the current rate limit window.
-    pub rate_limit_remaining: String,
-    /// The time at which the current rate limit window in [Unix time](https://en.wikipedia.org/wiki/Unix_time) format.
-    pub rate_limit_reset: String,
+    /// The rate limit reported by the API (`None` if the headers are missing).
+    pub rate_limit: Option<RateLimit>,
     /// The HTTP Status Code
     pub status: u16,
 }
@@ -140,14 +309,18 @@ pub struct DNSimpleEmptyResponse {
 //
 // With potential hundreds of result entries, it’s convenient to apply a filter and receive only the
 // interesting data.
-#[derive(Debug)]
+//
+// The filters are kept sorted by name, so the same filters always give the same query string.
+#[derive(Debug, Clone)]
 pub struct Filters {
-    pub filters: HashMap<String, String>,
+    pub filters: BTreeMap<String, String>,
 }
 
 impl Filters {
-    pub fn new(filters: HashMap<String, String>) -> Filters {
-        Filters { filters }
+    pub fn new(filters: impl IntoIterator<Item = (String, String)>) -> Filters {
+        Filters {
+            filters: filters.into_iter().collect(),
+        }
     }
 }
 
@@ -159,7 +332,7 @@ impl Filters {
 // order criteria (asc for ascending and desc for descending).
 //
 // The order of fields is relevant, as it will determine the priority of the sorting policies.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Sort {
     pub sort_by: String,
 }
@@ -170,7 +343,28 @@ impl Sort {
     }
 }
 
+impl RequestOptions {
+    /// Builds the options of a typed `*ListOptions`, leaving out the filters not set.
+    pub(crate) fn typed(
+        filters: Vec<(&str, Option<String>)>,
+        sort: Option<&str>,
+        paginate: Option<Paginate>,
+    ) -> RequestOptions {
+        let filters: BTreeMap<String, String> = filters
+            .into_iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value?)))
+            .collect();
+
+        RequestOptions {
+            filters: (!filters.is_empty()).then(|| Filters::new(filters)),
+            sort: sort.map(|sort_by| Sort::new(sort_by.to_string())),
+            paginate,
+        }
+    }
+}
+
 /// The pagination instructions for the request
+#[derive(Debug, Clone)]
 pub struct Paginate {
     /// The number of items you want
     pub per_page: u32,
@@ -200,78 +394,189 @@ pub fn new_client(sandbox: bool, token: String) -> Client {
         url = DEFAULT_SANDBOX_URL;
     }
 
-    Client {
+    Client::with_config(ClientConfig {
         base_url: String::from(url),
         user_agent: DEFAULT_USER_AGENT.to_owned() + VERSION,
-        auth_token: token,
-        _agent: ureq::Agent::new(),
-    }
+        credentials: Arc::new(Credentials::Bearer(token)),
+        account_id: None,
+        retry_policy: RetryPolicy::none(),
+        rate_limiter: None,
+        transport: Arc::new(UreqTransport::default()),
+        middlewares: Vec::new(),
+        dry_run: None,
+    })
 }
 
 impl Client {
+    /// Returns a `ClientBuilder` to configure a new client (timeouts, proxy, user agent...)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::Client;
+    ///
+    /// let client = Client::builder(String::from("AUTH_TOKEN"))
+    ///     .sandbox(true)
+    ///     .user_agent("my-app/1.0")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `token`: the bearer authentication token
+    pub fn builder(token: String) -> ClientBuilder {
+        ClientBuilder::new(token)
+    }
+
+    pub(crate) fn with_config(config: ClientConfig) -> Client {
+        Client {
+            config: Arc::new(config),
+        }
+    }
+
+    /// The configuration of this client only, copied first if it is shared with clones.
+    pub(crate) fn config_mut(&mut self) -> &mut ClientConfig {
+        Arc::make_mut(&mut self.config)
+    }
+
+    /// Returns a handle on the given account, whose services don't take the `account_id`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// let client = new_client(true, String::from("AUTH_TOKEN"));
+    /// let zones = client.account(1234).zones().list_zones(None).unwrap().data.unwrap();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    pub fn account(&self, account_id: impl Into<AccountId>) -> AccountScope {
+        AccountScope::new(self.clone(), account_id)
+    }
+
+    /// Returns a handle on the configured account, or on the account of the token (found with
+    /// a `whoami`) when no account id was configured
+    ///
+    /// Fails with `DNSimpleError::Configuration` for a user token, which can access several
+    /// accounts: use `account` with the account id instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// let client = new_client(true, String::from("AUTH_TOKEN"));
+    /// let account = client.default_account().unwrap();
+    /// let domains = account.domains().list_domains(None).unwrap().data.unwrap();
+    /// ```
+    pub fn default_account(&self) -> Result<AccountScope, DNSimpleError> {
+        if let Some(account_id) = self.config.account_id {
+            return Ok(AccountScope::new(self.clone(), account_id));
+        }
+
+        let whoami = self.identity().whoami()?;
+
+        Ok(AccountScope::new(self.clone(), account_id_of(whoami.data)?))
+    }
+
+    /// The account id the client was configured with, if any.
+    pub fn account_id(&self) -> Option<AccountId> {
+        self.config.account_id
+    }
+
     ///Returns the `accounts` service attached to this client
     pub fn accounts(&self) -> Accounts {
-        Accounts { client: self }
+        Accounts {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `contacts` service attached to this client
     pub fn contacts(&self) -> Contacts {
-        Contacts { client: self }
+        Contacts {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `certificates` service attached to this client
     pub fn certificates(&self) -> Certificates {
-        Certificates { client: self }
+        Certificates {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `domains` service attached to this client
     pub fn domains(&self) -> Domains {
-        Domains { client: self }
+        Domains {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `identity` service attached to this client
     pub fn identity(&self) -> Identity {
-        Identity { client: self }
+        Identity {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `oauth` service attached to this client
     pub fn oauth(&self) -> OAuth {
-        OAuth { client: self }
+        OAuth {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `registrar` service attached to this client
     pub fn registrar(&self) -> Registrar {
-        Registrar { client: self }
+        Registrar {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `services` service attached to this client
     pub fn services(&self) -> Services {
-        Services { client: self }
+        Services {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `templates` service attached to this client
     pub fn templates(&self) -> Templates {
-        Templates { client: self }
+        Templates {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `tlds` service attached to this endpoint
     pub fn tlds(&self) -> Tlds {
-        Tlds { client: self }
+        Tlds {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `vanity_name_servers` service attached to this endpoint
     pub fn vanity_name_servers(&self) -> VanityNameServers {
-        VanityNameServers { client: self }
+        VanityNameServers {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `webhooks` service attached to this endpoint
     pub fn webhooks(&self) -> Webhooks {
-        Webhooks { client: self }
+        Webhooks {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `zones` service attached to this endpoint
     pub fn zones(&self) -> Zones {
-        Zones { client: self }
+        Zones {
+            client: self.clone(),
+        }
     }
 
     /// Convenience function to change the base url in runtime (used internally for
@@ -289,12 +594,83 @@ impl Client {
     ///
     /// `url`: The url we want to change the base url to.
     pub fn set_base_url(&mut self, url: &str) {
-        self.base_url = String::from(url);
+        self.config_mut().base_url = String::from(url);
+    }
+
+    /// Changes the `RetryPolicy` used to re-issue requests failing with a transient error.
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::{Client, RetryPolicy, new_client};
+    /// let mut client = new_client(true, String::from("ACCESS_TOKEN"));
+    /// client.set_retry_policy(RetryPolicy::default());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `policy`: The policy to use from now on.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.config_mut().retry_policy = policy;
+    }
+
+    /// Holds the requests back (or fails them) before they exceed the rate limit.
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::{Client, RateLimitMode, RateLimiter, new_client};
+    /// let mut client = new_client(true, String::from("ACCESS_TOKEN"));
+    /// client.set_rate_limiter(RateLimiter::new(100, RateLimitMode::Block));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `limiter`: The limiter to use from now on (it can be shared with other clients).
+    pub fn set_rate_limiter(&mut self, limiter: RateLimiter) {
+        self.config_mut().rate_limiter = Some(limiter);
+    }
+
+    /// Changes the `Transport` sending the requests over the wire.
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::{Client, UreqTransport, new_client};
+    /// let mut client = new_client(true, String::from("ACCESS_TOKEN"));
+    /// client.set_transport(UreqTransport::new(ureq::Agent::new()));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `transport`: The transport to use from now on.
+    pub fn set_transport(&mut self, transport: impl Transport + 'static) {
+        self.config_mut().transport = Arc::new(transport);
+    }
+
+    /// Puts the client in dry-run mode: its mutating requests are recorded in the `DryRun`
+    /// instead of being sent.
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::{Client, DryRun, new_client};
+    /// let mut client = new_client(true, String::from("ACCESS_TOKEN"));
+    /// let plan = DryRun::new();
+    /// client.set_dry_run(plan.clone());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `dry_run`: Where to record the planned operations.
+    pub fn set_dry_run(&mut self, dry_run: DryRun) {
+        self.config_mut().dry_run = Some(dry_run);
+    }
+
+    /// Adds a `Middleware` at the end of the chain every request goes through.
+    ///
+    /// # Arguments
+    ///
+    /// `middleware`: The middleware, run after the ones already added.
+    pub fn add_middleware(&mut self, middleware: impl Middleware + 'static) {
+        self.config_mut().middlewares.push(Arc::new(middleware));
     }
 
     /// Returns the current url (including the `API_VERSION` as part of the path).
     pub fn versioned_url(&self) -> String {
-        let mut url = String::from(&self.base_url);
+        let mut url = String::from(&self.config.base_url);
         url.push('/');
         url.push_str(API_VERSION);
         url
@@ -397,197 +773,347 @@ impl Client {
 
     fn call_with_payload<E: Endpoint>(
         &self,
-        request: Request,
+        mut request: HttpRequest,
         data: Value,
     ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
-        self.process_response::<E>(request.send_json(data))
+        request.body = Some(data);
+        Self::build_dnsimple_response::<E>(self.send(request)?)
     }
 
     fn call<E: Endpoint>(
         &self,
-        request: Request,
+        request: HttpRequest,
     ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
-        self.process_response::<E>(request.call())
+        Self::build_dnsimple_response::<E>(self.send(request)?)
     }
 
-    fn process_response<E: Endpoint>(
-        &self,
-        result: Result<Response, Error>,
-    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
-        match result {
-            Ok(response) => Self::build_dnsimple_response::<E>(response),
-            Err(Error::Status(code, response)) => {
-                Err(DNSimpleError::parse_response(code, response))
+    fn call_empty(&self, request: HttpRequest) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        Self::build_empty_dnsimple_response(self.send(request)?)
+    }
+
+    /// Sends the request, traced when the `tracing` feature is enabled (or only records it
+    /// when it is a mutating one and the client is in dry-run mode).
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+        let method = request.method.clone();
+        let path = request
+            .url
+            .strip_prefix(&self.versioned_url())
+            .unwrap_or(&request.url)
+            .to_string();
+
+        if let Some(ref dry_run) = self.config.dry_run {
+            if method != "GET" {
+                return Ok(dry_run.plan(PlannedOperation {
+                    method,
+                    path,
+                    query: request.query,
+                    payload: request.body,
+                }));
             }
-            Err(Error::Transport(transport)) => Err(DNSimpleError::parse_transport(transport)),
         }
+
+        instrumentation::instrument(&method, &path, || self.send_with_retries(request))
     }
 
-    fn call_empty(&self, request: Request) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        match request.call() {
-            Ok(response) => Self::build_empty_dnsimple_response(response),
-            Err(Error::Status(code, response)) => {
-                Err(DNSimpleError::parse_response(code, response))
+    /// Sends the request, retrying it as long as the `RetryPolicy` of the client allows it.
+    fn send_with_retries(&self, request: HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+        let mut attempt = 1;
+
+        loop {
+            if let Some(ref limiter) = self.config.rate_limiter {
+                while let Some(wait) = limiter.acquire()? {
+                    thread::sleep(wait);
+                }
+            }
+
+            let response = self.dispatch(self.authorize(request.clone())?)?;
+            instrumentation::record_response(&response);
+            if let Some(ref limiter) = self.config.rate_limiter {
+                limiter.update(Self::rate_limit_of(&response));
+            }
+
+            if response.is_success() {
+                return Ok(response);
+            }
+
+            let reset = response.header(RATE_LIMIT_RESET);
+            match self
+                .config
+                .retry_policy
+                .delay(&request.method, attempt, response.status, reset)
+            {
+                Some(wait) => thread::sleep(wait),
+                None => return Err(DNSimpleError::parse_response(&request, &response)),
             }
-            Err(Error::Transport(transport)) => Err(DNSimpleError::parse_transport(transport)),
+
+            attempt += 1;
         }
     }
 
+    /// Adds the `Authorization` header, with the current credentials, to the request.
+    fn authorize(&self, mut request: HttpRequest) -> Result<HttpRequest, DNSimpleError> {
+        let credentials = self.config.credentials.credentials()?;
+        request
+            .headers
+            .push((String::from("Authorization"), credentials.authorization()));
+        Ok(request)
+    }
+
+    /// Sends the request through the middlewares and the transport.
+    fn dispatch(&self, request: HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+        Next::new(&self.config.middlewares, self.config.transport.as_ref()).run(request)
+    }
+
+    fn rate_limit_of(response: &HttpResponse) -> Option<RateLimit> {
+        RateLimit::from_headers(
+            response.header(RATE_LIMIT_LIMIT),
+            response.header(RATE_LIMIT_REMAINING),
+            response.header(RATE_LIMIT_RESET),
+        )
+    }
+
     fn build_dnsimple_response<E: Endpoint>(
-        resp: Response,
+        resp: HttpResponse,
     ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
-        let rate_limit = Self::extract_rate_limit_limit_header(&resp)?;
-        let rate_limit_remaining = Self::extract_rate_limit_remaining_header(&resp)?;
-        let rate_limit_reset = Self::extract_rate_limit_reset_header(&resp)?;
-
-        let status = resp.status();
+        let rate_limit = Self::rate_limit_of(&resp);
 
-        let json = resp
-            .into_json::<Value>()
-            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
-        let data = serde_json::from_value(json!(json.get("data")))
-            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
-        let pagination = serde_json::from_value(json!(json.get("pagination")))
-            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
-        let body = serde_json::from_value(json)
+        let json = serde_json::from_str::<Value>(&resp.body)
             .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
 
-        Ok(DNSimpleResponse {
-            rate_limit,
-            rate_limit_remaining,
-            rate_limit_reset,
-            status,
-            data,
-            pagination,
-            body,
+        build_response(rate_limit, resp.status, json)
+    }
+
+    fn build_empty_dnsimple_response(
+        response: HttpResponse,
+    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        Ok(DNSimpleEmptyResponse {
+            rate_limit: Self::rate_limit_of(&response),
+            status: response.status,
         })
     }
 
-    fn extract_rate_limit_reset_header(resp: &Response) -> Result<String, DNSimpleError> {
-        match resp.header("X-RateLimit-Reset") {
-            Some(header) => Ok(header.to_string()),
-            None => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot parse the X-RateLimit-Reset header",
-            ))),
-        }
+    fn build_get_request(&self, path: &&str, options: Option<RequestOptions>) -> HttpRequest {
+        let mut request = self.build_request("GET", path);
+        request.query = query_parameters(options);
+        request
     }
 
-    fn extract_rate_limit_remaining_header(resp: &Response) -> Result<String, DNSimpleError> {
-        match resp.header("X-RateLimit-Remaining") {
-            Some(header) => Ok(header.to_string()),
-            None => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot parse the X-RateLimit-Remaining header",
-            ))),
-        }
+    pub fn build_post_request(&self, path: &&str) -> HttpRequest {
+        self.build_request("POST", path)
     }
 
-    fn extract_rate_limit_limit_header(resp: &Response) -> Result<String, DNSimpleError> {
-        match resp.header("X-RateLimit-Limit") {
-            Some(header) => Ok(header.to_string()),
-            None => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot parse the X-RateLimit-Limit header",
-            ))),
-        }
+    pub fn build_put_request(&self, path: &&str) -> HttpRequest {
+        self.build_request("PUT", path)
     }
 
-    fn build_empty_dnsimple_response(
-        response: Response,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        Ok(DNSimpleEmptyResponse {
-            rate_limit: Self::extract_rate_limit_limit_header(&response)?,
-            rate_limit_remaining: Self::extract_rate_limit_remaining_header(&response)?,
-            rate_limit_reset: Self::extract_rate_limit_reset_header(&response)?,
-            status: response.status(),
-        })
+    pub fn build_patch_request(&self, path: &&str) -> HttpRequest {
+        self.build_request("PATCH", path)
     }
 
-    fn build_get_request(&self, path: &&str, options: Option<RequestOptions>) -> Request {
-        let mut request = self
-            ._agent
-            .get(&self.url(path))
-            .set("User-Agent", &self.user_agent)
-            .set("Accept", "application/json");
+    fn build_delete_request(&self, path: &&str) -> HttpRequest {
+        self.build_request("DELETE", path)
+    }
 
-        if let Some(options) = options {
-            if let Some(pagination) = options.paginate {
-                request = request.query("page", &pagination.page.to_string());
-                request = request.query("per_page", &pagination.per_page.to_string())
-            }
+    fn build_request(&self, method: &str, path: &str) -> HttpRequest {
+        let mut request = HttpRequest::new(method, &self.url(path));
+        request.headers = vec![
+            (String::from("User-Agent"), self.config.user_agent.clone()),
+            (String::from("Accept"), String::from("application/json")),
+        ];
+        request
+    }
 
-            if let Some(filters) = options.filters {
-                for (key, value) in filters.filters {
-                    request = request.query(&key, &value);
-                }
-            }
+    fn url(&self, path: &str) -> String {
+        let mut url = self.versioned_url();
+        url.push_str(path);
+        url
+    }
+}
 
-            if let Some(sort) = options.sort {
-                request = request.query("sort", &sort.sort_by);
-            }
-        }
+impl ApiClient for Client {
+    type Result<'a, T: 'a> = Result<T, DNSimpleError>;
 
-        self.add_headers_to_request(request)
+    fn get<E: Endpoint>(
+        &self,
+        path: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        Client::get::<E>(self, path, options)
     }
 
-    pub fn build_post_request(&self, path: &&str) -> Request {
-        let request = self
-            ._agent
-            .post(&self.url(path))
-            .set("User-Agent", &self.user_agent)
-            .set("Accept", "application/json");
-        self.add_headers_to_request(request)
+    fn post<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        Client::post::<E>(self, path, payload_to_json(data)?)
     }
 
-    pub fn build_put_request(&self, path: &&str) -> Request {
-        let request = self
-            ._agent
-            .put(&self.url(path))
-            .set("User-Agent", &self.user_agent)
-            .set("Accept", "application/json");
-        self.add_headers_to_request(request)
+    fn empty_post(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        Client::empty_post(self, path)
     }
 
-    pub fn build_patch_request(&self, path: &&str) -> Request {
-        let request = self
-            ._agent
-            .request("PATCH", &self.url(path))
-            .set("User-Agent", &self.user_agent)
-            .set("Accept", "application/json");
-        self.add_headers_to_request(request)
+    fn put<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        Client::put::<E>(self, path, payload_to_json(data)?)
     }
 
-    fn build_delete_request(&self, path: &&str) -> Request {
-        let request = self
-            ._agent
-            .delete(&self.url(path))
-            .set("User-Agent", &self.user_agent)
-            .set("Accept", "application/json");
-        self.add_headers_to_request(request)
+    fn empty_put(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        Client::empty_put(self, path)
     }
 
-    fn add_headers_to_request(&self, request: Request) -> Request {
-        let auth_token = &format!("Bearer {}", self.auth_token);
-        request.set("Authorization", auth_token.as_str())
+    fn patch<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        Client::patch::<E>(self, path, payload_to_json(data)?)
     }
 
-    fn url(&self, path: &str) -> String {
-        let mut url = self.versioned_url();
-        url.push_str(path);
-        url
+    fn delete(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        Client::delete(self, path)
+    }
+
+    fn delete_with_response<E: Endpoint>(
+        &self,
+        path: &str,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        Client::delete_with_response::<E>(self, path)
+    }
+
+    fn unauthenticated_post<T: DeserializeOwned + 'static>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Result<T, DNSimpleError> {
+        let mut request = HttpRequest::new("POST", &self.url(path));
+        request.body = Some(
+            serde_json::to_value(data)
+                .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?,
+        );
+
+        let response = self.dispatch(request.clone())?;
+        if !response.is_success() {
+            return Err(DNSimpleError::parse_response(&request, &response));
+        }
+
+        serde_json::from_str::<T>(&response.body)
+            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))
+    }
+
+    fn fail<T: 'static>(&self, error: DNSimpleError) -> Result<T, DNSimpleError> {
+        Err(error)
     }
 }
 
+pub(crate) const RATE_LIMIT_LIMIT: &str = "X-RateLimit-Limit";
+pub(crate) const RATE_LIMIT_REMAINING: &str = "X-RateLimit-Remaining";
+pub(crate) const RATE_LIMIT_RESET: &str = "X-RateLimit-Reset";
+
+/// Serializes the payload of a request, failing the call if it cannot be represented as JSON.
+pub(crate) fn payload_to_json(data: impl Serialize) -> Result<Value, DNSimpleError> {
+    serde_json::to_value(data).map_err(|_| {
+        DNSimpleError::Deserialization(String::from("Cannot deserialize json payload"))
+    })
+}
+
+/// Turns the `RequestOptions` into the query parameters understood by the API.
+pub(crate) fn query_parameters(options: Option<RequestOptions>) -> Vec<(String, String)> {
+    let mut query = Vec::new();
+
+    if let Some(options) = options {
+        if let Some(pagination) = options.paginate {
+            query.push(("page".to_string(), pagination.page.to_string()));
+            query.push(("per_page".to_string(), pagination.per_page.to_string()));
+        }
+
+        if let Some(filters) = options.filters {
+            query.extend(filters.filters);
+        }
+
+        if let Some(sort) = options.sort {
+            query.push(("sort".to_string(), sort.sort_by));
+        }
+    }
+
+    query
+}
+
+pub(crate) fn build_response<T: DeserializeOwned>(
+    rate_limit: Option<RateLimit>,
+    status: u16,
+    json: Value,
+) -> Result<DNSimpleResponse<T>, DNSimpleError> {
+    let data = serde_json::from_value(json!(json.get("data")))
+        .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
+    let pagination = serde_json::from_value(json!(json.get("pagination")))
+        .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
+    let body =
+        serde_json::from_value(json).map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
+
+    Ok(DNSimpleResponse {
+        rate_limit,
+        status,
+        data,
+        pagination,
+        body,
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::dnsimple::{new_client, DEFAULT_SANDBOX_URL, DEFAULT_USER_AGENT, VERSION};
+    use crate::dnsimple::zones::Zones;
+    use crate::dnsimple::{
+        new_client, AccountScope, Client, RateLimit, DEFAULT_SANDBOX_URL, DEFAULT_USER_AGENT,
+        VERSION,
+    };
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
     #[test]
     fn creates_a_client() {
         let token = "some-auth-token";
         let client = new_client(true, String::from(token));
 
-        assert_eq!(client.base_url, DEFAULT_SANDBOX_URL);
-        assert_eq!(client.user_agent, DEFAULT_USER_AGENT.to_owned() + VERSION);
-        assert_eq!(client.auth_token, token);
+        assert_eq!(client.config.base_url, DEFAULT_SANDBOX_URL);
+        assert_eq!(
+            client.config.user_agent,
+            DEFAULT_USER_AGENT.to_owned() + VERSION
+        );
+        assert_eq!(
+            Some(String::from("Bearer some-auth-token")),
+            client
+                .config
+                .credentials
+                .credentials()
+                .ok()
+                .map(|credentials| credentials.authorization())
+        );
+    }
+
+    #[test]
+    fn can_be_shared_between_threads() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+
+        assert_shareable::<Client>();
+        assert_shareable::<Zones>();
+        assert_shareable::<AccountScope>();
+    }
+
+    #[test]
+    fn clones_share_the_configuration_until_changed() {
+        let client = new_client(true, String::from("token"));
+        let mut clone = client.clone();
+
+        assert!(Arc::ptr_eq(&client.config, &clone.config));
+
+        clone.set_base_url("https://example.com");
+
+        assert!(!Arc::ptr_eq(&client.config, &clone.config));
+        assert_eq!(client.config.base_url, DEFAULT_SANDBOX_URL);
     }
 
     #[test]
@@ -597,4 +1123,44 @@ mod tests {
 
         assert_eq!(client.versioned_url(), "https://example.com/v2");
     }
+
+    #[test]
+    fn parses_the_rate_limit_headers() {
+        let rate_limit = RateLimit::from_headers(Some("4000"), Some("3991"), Some("1450451976"));
+
+        assert_eq!(
+            Some(RateLimit {
+                limit: 4000,
+                remaining: 3991,
+                reset: UNIX_EPOCH + Duration::from_secs(1450451976),
+            }),
+            rate_limit
+        );
+    }
+
+    #[test]
+    fn tolerates_missing_or_invalid_rate_limit_headers() {
+        assert_eq!(None, RateLimit::from_headers(None, Some("2"), Some("0")));
+        assert_eq!(None, RateLimit::from_headers(Some("2"), None, Some("0")));
+        assert_eq!(
+            None,
+            RateLimit::from_headers(Some("2"), Some("2"), Some("never"))
+        );
+    }
+
+    #[test]
+    fn computes_the_time_until_reset() {
+        let rate_limit = RateLimit {
+            limit: 4000,
+            remaining: 0,
+            reset: SystemTime::now() + Duration::from_secs(60),
+        };
+        let expired = RateLimit {
+            reset: UNIX_EPOCH,
+            ..rate_limit
+        };
+
+        assert!(rate_limit.time_until_reset() > Duration::from_secs(59));
+        assert_eq!(Duration::ZERO, expired.time_until_reset());
+    }
 }
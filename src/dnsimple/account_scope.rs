@@ -0,0 +1,1054 @@
+use crate::dnsimple::certificates::{
+    Certificate, CertificateBundle, CertificatePrivateKey, Certificates, LetsEncryptPurchase,
+    LetsEncryptPurchasePayload, LetsEncryptPurchaseRenewal, LetsEncryptPurchaseRenewalPayload,
+};
+use crate::dnsimple::contacts::{Contact, ContactPayload, Contacts};
+use crate::dnsimple::domains::{Domain, Domains};
+use crate::dnsimple::domains_collaborators::Collaborator;
+use crate::dnsimple::domains_dnssec::Dnssec;
+use crate::dnsimple::domains_email_forwards::{
+    EmailForward, EmailForwardPayload, EmailForwardsInList,
+};
+use crate::dnsimple::domains_push::{DomainPush, InitiatePushPayload};
+use crate::dnsimple::domains_signer_records::{
+    DelegationSignerRecord, DelegationSignerRecordPayload,
+};
+use crate::dnsimple::identity::WhoamiData;
+use crate::dnsimple::ids::{
+    AccountId, CertificateId, CertificateRenewalId, CollaboratorId, ContactId,
+    DelegationSignerRecordId, DomainRef, DomainRegistrationId, DomainRenewalId, DomainTransferId,
+    EmailForwardId, PushId, TemplateRecordId, TemplateRef, WebhookId, ZoneRecordId,
+};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::registrar::{
+    DomainCheck, DomainPrice, DomainRegistration, DomainRegistrationPayload, DomainRenewal,
+    DomainRenewalPayload, DomainTransfer, DomainTransferPayload, Registrar,
+};
+use crate::dnsimple::registrar_name_servers::VanityNameServer;
+use crate::dnsimple::registrar_whois_privacy::{WhoisPrivacy, WhoisPrivacyRenewal};
+use crate::dnsimple::templates::{
+    Template, TemplatePayload, TemplateRecord, TemplateRecordPayload, Templates,
+};
+use crate::dnsimple::webhooks::{Webhook, Webhooks};
+use crate::dnsimple::zones::{Zone, ZoneDistribution, ZoneFile, Zones};
+use crate::dnsimple::zones_records::{ZoneRecord, ZoneRecordPayload, ZoneRecordUpdatePayload};
+use crate::dnsimple::{ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, RequestOptions};
+use crate::errors::DNSimpleError;
+/// A handle on one account, giving the services without the `account_id` argument
+///
+/// Get it from `Client::account` when the account id is known or from
+/// `Client::default_account` to use the account of an account token.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+///
+/// let client = new_client(true, String::from("AUTH_TOKEN"));
+/// let account = client.account(1234);
+///
+/// let zones = account.zones().list_zones(None).unwrap().data.unwrap();
+/// let records = account.zones().list_zone_records("example.com", None).unwrap().data.unwrap();
+/// ```
+#[derive(Clone)]
+pub struct AccountScope<C = Client> {
+    client: C,
+    account_id: AccountId,
+}
+
+impl<C: ApiClient + Clone> AccountScope<C> {
+    /// Creates a handle on the given account
+    ///
+    /// # Arguments
+    ///
+    /// `client`: The client sending the requests
+    /// `account_id`: The account id
+    pub fn new(client: C, account_id: impl Into<AccountId>) -> AccountScope<C> {
+        AccountScope {
+            client,
+            account_id: account_id.into(),
+        }
+    }
+
+    /// The id of the account.
+    pub fn id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the `certificates` service scoped to the account
+    pub fn certificates(&self) -> AccountCertificates<C> {
+        AccountCertificates {
+            service: Certificates {
+                client: self.client.clone(),
+            },
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `contacts` service scoped to the account
+    pub fn contacts(&self) -> AccountContacts<C> {
+        AccountContacts {
+            service: Contacts {
+                client: self.client.clone(),
+            },
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `domains` service scoped to the account
+    pub fn domains(&self) -> AccountDomains<C> {
+        AccountDomains {
+            service: Domains {
+                client: self.client.clone(),
+            },
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `registrar` service scoped to the account
+    pub fn registrar(&self) -> AccountRegistrar<C> {
+        AccountRegistrar {
+            service: Registrar {
+                client: self.client.clone(),
+            },
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `templates` service scoped to the account
+    pub fn templates(&self) -> AccountTemplates<C> {
+        AccountTemplates {
+            service: Templates {
+                client: self.client.clone(),
+            },
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `webhooks` service scoped to the account
+    pub fn webhooks(&self) -> AccountWebhooks<C> {
+        AccountWebhooks {
+            service: Webhooks {
+                client: self.client.clone(),
+            },
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `zones` service scoped to the account
+    pub fn zones(&self) -> AccountZones<C> {
+        AccountZones {
+            service: Zones {
+                client: self.client.clone(),
+            },
+            account_id: self.account_id,
+        }
+    }
+}
+
+/// The account id of a `whoami`, failing for the user tokens.
+pub(crate) fn account_id_of(whoami: Option<WhoamiData>) -> Result<u64, DNSimpleError> {
+    whoami
+        .and_then(|whoami| whoami.account)
+        .map(|account| account.id)
+        .ok_or_else(|| {
+            DNSimpleError::Configuration(String::from(
+                "the token is not an account token, use `account` with the account id instead",
+            ))
+        })
+}
+
+/// The `zones` service scoped to an account, see `Zones`
+#[derive(Clone)]
+pub struct AccountZones<C = Client> {
+    service: Zones<C>,
+    account_id: AccountId,
+}
+
+impl<C: ApiClient> AccountZones<C> {
+    /// Activates DNS resolution for the zone in the account.
+    pub fn activate_dns(
+        &self,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Zone>> {
+        self.service.activate_dns(self.account_id, zone)
+    }
+
+    /// Deactivates DNS resolution for the zone in the account.
+    pub fn deactivate_dns(
+        &self,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Zone>> {
+        self.service.deactivate_dns(self.account_id, zone)
+    }
+
+    /// Lists the zones in the account.
+    pub fn list_zones(
+        &self,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Zone>>> {
+        self.service.list_zones(self.account_id, options)
+    }
+
+    /// Retrieve a zone
+    pub fn get_zone(&self, zone: impl Into<DomainRef>) -> C::Result<'_, DNSimpleResponse<Zone>> {
+        self.service.get_zone(self.account_id, zone)
+    }
+
+    /// Download a zone file
+    pub fn get_zone_file(
+        &self,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneFile>> {
+        self.service.get_zone_file(self.account_id, zone)
+    }
+
+    /// Check zone distribution
+    pub fn check_zone_distribution(
+        &self,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneDistribution>> {
+        self.service.check_zone_distribution(self.account_id, zone)
+    }
+
+    /// List zone records
+    pub fn list_zone_records(
+        &self,
+        zone: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<ZoneRecord>>> {
+        self.service
+            .list_zone_records(self.account_id, zone, options)
+    }
+
+    /// Create a zone record
+    pub fn create_zone_record(
+        &self,
+        zone: impl Into<DomainRef>,
+        payload: ZoneRecordPayload,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneRecord>> {
+        self.service
+            .create_zone_record(self.account_id, zone, payload)
+    }
+
+    /// Retrieve a zone record
+    pub fn get_zone_record(
+        &self,
+        zone: impl Into<DomainRef>,
+        record: impl Into<ZoneRecordId>,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneRecord>> {
+        self.service.get_zone_record(self.account_id, zone, record)
+    }
+
+    /// Update a zone record
+    pub fn update_zone_record(
+        &self,
+        zone: impl Into<DomainRef>,
+        record: impl Into<ZoneRecordId>,
+        payload: ZoneRecordUpdatePayload,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneRecord>> {
+        self.service
+            .update_zone_record(self.account_id, zone, record, payload)
+    }
+
+    /// Delete a zone record
+    pub fn delete_zone_record(
+        &self,
+        zone: impl Into<DomainRef>,
+        record: impl Into<ZoneRecordId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service
+            .delete_zone_record(self.account_id, zone, record)
+    }
+
+    /// Check zone record distribution
+    pub fn check_zone_record_distribution(
+        &self,
+        zone: impl Into<DomainRef>,
+        record: impl Into<ZoneRecordId>,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneDistribution>> {
+        self.service
+            .check_zone_record_distribution(self.account_id, zone, record)
+    }
+}
+
+impl AccountZones<Client> {
+    /// Lists all the zones, fetching the pages lazily (see `list_zones`)
+    pub fn list_zones_iter(&self, options: Option<RequestOptions>) -> Paginator<Zone> {
+        self.service.list_zones_iter(self.account_id, options)
+    }
+
+    /// Lists all the zone records, fetching the pages lazily (see `list_zone_records`)
+    pub fn list_zone_records_iter(
+        &self,
+        zone: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<ZoneRecord> {
+        self.service
+            .list_zone_records_iter(self.account_id, zone, options)
+    }
+}
+
+/// The `domains` service scoped to an account, see `Domains`
+#[derive(Clone)]
+pub struct AccountDomains<C = Client> {
+    service: Domains<C>,
+    account_id: AccountId,
+}
+
+impl<C: ApiClient> AccountDomains<C> {
+    /// Lists the domains in the account
+    pub fn list_domains(
+        &self,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Domain>>> {
+        self.service.list_domains(self.account_id, options)
+    }
+
+    /// Adds a domain to the account.
+    pub fn create_domain(&self, name: String) -> C::Result<'_, DNSimpleResponse<Domain>> {
+        self.service.create_domain(self.account_id, name)
+    }
+
+    /// Retrieves the details of an existing domain.
+    pub fn get_domain(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Domain>> {
+        self.service.get_domain(self.account_id, domain)
+    }
+
+    /// Permanently deletes a domain from the account. It cannot be undone.
+    pub fn delete_domain(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.delete_domain(self.account_id, domain)
+    }
+
+    /// List collaborators for the domain in the account.
+    pub fn list_collaborators(
+        &self,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Collaborator>>> {
+        self.service
+            .list_collaborators(self.account_id, domain, options)
+    }
+
+    /// Adds a collaborator to the domain in the account.
+    pub fn add_collaborator(
+        &self,
+        domain: impl Into<DomainRef>,
+        email: &str,
+    ) -> C::Result<'_, DNSimpleResponse<Collaborator>> {
+        self.service
+            .add_collaborator(self.account_id, domain, email)
+    }
+
+    /// Removes a collaborator from a domain
+    pub fn remove_collaborator(
+        &self,
+        domain: impl Into<DomainRef>,
+        collaborator_id: impl Into<CollaboratorId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service
+            .remove_collaborator(self.account_id, domain, collaborator_id)
+    }
+
+    /// Enable DNSSEC for the domain in the account. This will sign the zone.
+    pub fn enable_dnssec(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Dnssec>> {
+        self.service.enable_dnssec(self.account_id, domain)
+    }
+
+    /// Disable DNSSEC for the domain in the account.
+    pub fn disable_dnssec(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.disable_dnssec(self.account_id, domain)
+    }
+
+    /// Get the status of DNSSEC, indicating whether it is currently enabled or disabled.
+    pub fn get_dnssec(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Dnssec>> {
+        self.service.get_dnssec(self.account_id, domain)
+    }
+
+    /// List email forwards for the domain in the account.
+    pub fn list_email_forwards(
+        &self,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<EmailForwardsInList>>> {
+        self.service
+            .list_email_forwards(self.account_id, domain, options)
+    }
+
+    /// Create an email forward
+    pub fn create_email_forward(
+        &self,
+        domain: impl Into<DomainRef>,
+        payload: EmailForwardPayload,
+    ) -> C::Result<'_, DNSimpleResponse<EmailForward>> {
+        self.service
+            .create_email_forward(self.account_id, domain, payload)
+    }
+
+    /// Retrieve an email forward
+    pub fn get_email_forward(
+        &self,
+        domain: impl Into<DomainRef>,
+        email_forward: impl Into<EmailForwardId>,
+    ) -> C::Result<'_, DNSimpleResponse<EmailForward>> {
+        self.service
+            .get_email_forward(self.account_id, domain, email_forward)
+    }
+
+    /// Delete the email forward from the domain.
+    pub fn delete_email_forward(
+        &self,
+        domain: impl Into<DomainRef>,
+        email_forward: impl Into<EmailForwardId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service
+            .delete_email_forward(self.account_id, domain, email_forward)
+    }
+
+    /// Initiate a push
+    pub fn initiate_push(
+        &self,
+        domain: impl Into<DomainRef>,
+        payload: InitiatePushPayload,
+    ) -> C::Result<'_, DNSimpleResponse<DomainPush>> {
+        self.service.initiate_push(self.account_id, domain, payload)
+    }
+
+    /// List pending pushes for the target account.
+    pub fn list_pushes(
+        &self,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<DomainPush>>> {
+        self.service.list_pushes(self.account_id, options)
+    }
+
+    /// Accept a push
+    pub fn accept_push(&self, push_id: impl Into<PushId>) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.accept_push(self.account_id, push_id)
+    }
+
+    /// Reject a push
+    pub fn reject_push(&self, push_id: impl Into<PushId>) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.reject_push(self.account_id, push_id)
+    }
+
+    /// List delegation signer records for the domain in the account.
+    pub fn list_delegation_signer_records(
+        &self,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<DelegationSignerRecord>>> {
+        self.service
+            .list_delegation_signer_records(self.account_id, domain, options)
+    }
+
+    /// Creates a delegation signer record
+    pub fn create_delegation_signer_record(
+        &self,
+        domain: impl Into<DomainRef>,
+        payload: DelegationSignerRecordPayload,
+    ) -> C::Result<'_, DNSimpleResponse<DelegationSignerRecord>> {
+        self.service
+            .create_delegation_signer_record(self.account_id, domain, payload)
+    }
+
+    /// Get the delegation signer record under the domain for the account
+    pub fn get_delegation_signer_record(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<DelegationSignerRecord>> {
+        self.service
+            .get_delegation_signer_record(self.account_id, domain)
+    }
+
+    /// Delete a Delegation Signer record
+    pub fn delete_delegation_signer_record(
+        &self,
+        domain: impl Into<DomainRef>,
+        delegation_signer_record_id: impl Into<DelegationSignerRecordId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.delete_delegation_signer_record(
+            self.account_id,
+            domain,
+            delegation_signer_record_id,
+        )
+    }
+}
+
+impl AccountDomains<Client> {
+    /// Lists all the domains, fetching the pages lazily (see `list_domains`)
+    pub fn list_domains_iter(&self, options: Option<RequestOptions>) -> Paginator<Domain> {
+        self.service.list_domains_iter(self.account_id, options)
+    }
+
+    /// Lists all the collaborators, fetching the pages lazily (see `list_collaborators`)
+    pub fn list_collaborators_iter(
+        &self,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Collaborator> {
+        self.service
+            .list_collaborators_iter(self.account_id, domain, options)
+    }
+
+    /// Lists all the email forwards, fetching the pages lazily (see `list_email_forwards`)
+    pub fn list_email_forwards_iter(
+        &self,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<EmailForwardsInList> {
+        self.service
+            .list_email_forwards_iter(self.account_id, domain, options)
+    }
+
+    /// Lists all the pushes, fetching the pages lazily (see `list_pushes`)
+    pub fn list_pushes_iter(&self, options: Option<RequestOptions>) -> Paginator<DomainPush> {
+        self.service.list_pushes_iter(self.account_id, options)
+    }
+
+    /// Lists all the delegation signer records, fetching the pages lazily (see `list_delegation_signer_records`)
+    pub fn list_delegation_signer_records_iter(
+        &self,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<DelegationSignerRecord> {
+        self.service
+            .list_delegation_signer_records_iter(self.account_id, domain, options)
+    }
+}
+
+/// The `registrar` service scoped to an account, see `Registrar`
+#[derive(Clone)]
+pub struct AccountRegistrar<C = Client> {
+    service: Registrar<C>,
+    account_id: AccountId,
+}
+
+impl<C: ApiClient> AccountRegistrar<C> {
+    /// Checks a domain name for availability.
+    pub fn check_domain(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainCheck>> {
+        self.service.check_domain(self.account_id, domain)
+    }
+
+    /// Get a domain’s price for registration, renewal, and transfer.
+    pub fn get_domain_prices(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainPrice>> {
+        self.service.get_domain_prices(self.account_id, domain)
+    }
+
+    /// Get the details of an existing domain registration.
+    pub fn get_domain_registration(
+        &self,
+        domain: impl Into<DomainRef>,
+        domain_registration_id: impl Into<DomainRegistrationId>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainRegistration>> {
+        self.service
+            .get_domain_registration(self.account_id, domain, domain_registration_id)
+    }
+
+    /// Get the details of an existing domain renewal.
+    pub fn get_domain_renewal(
+        &self,
+        domain: impl Into<DomainRef>,
+        domain_renewal_id: impl Into<DomainRenewalId>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainRenewal>> {
+        self.service
+            .get_domain_renewal(self.account_id, domain, domain_renewal_id)
+    }
+
+    /// Registers a domain.
+    pub fn register_domain(
+        &self,
+        domain: impl Into<DomainRef>,
+        payload: DomainRegistrationPayload,
+    ) -> C::Result<'_, DNSimpleResponse<DomainRegistration>> {
+        self.service
+            .register_domain(self.account_id, domain, payload)
+    }
+
+    /// Transfer a domain name from another domain registrar into DNSimple.
+    pub fn transfer_domain(
+        &self,
+        domain: impl Into<DomainRef>,
+        payload: DomainTransferPayload,
+    ) -> C::Result<'_, DNSimpleResponse<DomainTransfer>> {
+        self.service
+            .transfer_domain(self.account_id, domain, payload)
+    }
+
+    /// Retrieves the details of an existing domain transfer.
+    pub fn get_domain_transfer(
+        &self,
+        domain: impl Into<DomainRef>,
+        domain_transfer: impl Into<DomainTransferId>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainTransfer>> {
+        self.service
+            .get_domain_transfer(self.account_id, domain, domain_transfer)
+    }
+
+    /// Cancels an in progress domain transfer.
+    pub fn cancel_domain_transfer(
+        &self,
+        domain: impl Into<DomainRef>,
+        domain_transfer: impl Into<DomainTransferId>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainTransfer>> {
+        self.service
+            .cancel_domain_transfer(self.account_id, domain, domain_transfer)
+    }
+
+    /// Renews a domain.
+    pub fn renew_domain(
+        &self,
+        domain: impl Into<DomainRef>,
+        payload: DomainRenewalPayload,
+    ) -> C::Result<'_, DNSimpleResponse<DomainRenewal>> {
+        self.service.renew_domain(self.account_id, domain, payload)
+    }
+
+    /// Authorize a domain transfer out
+    pub fn transfer_domain_out(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.transfer_domain_out(self.account_id, domain)
+    }
+
+    /// Enable domain auto-renewal
+    pub fn enable_domain_auto_renewal(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service
+            .enable_domain_auto_renewal(self.account_id, domain)
+    }
+
+    /// Disable domain auto-renewal
+    pub fn disable_domain_auto_renewal(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service
+            .disable_domain_auto_renewal(self.account_id, domain)
+    }
+
+    /// List name servers for the domain in the account.
+    pub fn get_domain_delegation(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<String>>> {
+        self.service.get_domain_delegation(self.account_id, domain)
+    }
+
+    /// Change domain name servers
+    pub fn change_domain_delegation(
+        &self,
+        domain: impl Into<DomainRef>,
+        server_names: Vec<&str>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<String>>> {
+        self.service
+            .change_domain_delegation(self.account_id, domain, server_names)
+    }
+
+    /// Delegate to vanity name servers
+    pub fn change_domain_delegation_to_vanity(
+        &self,
+        domain: impl Into<DomainRef>,
+        server_names: Vec<&str>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<VanityNameServer>>> {
+        self.service
+            .change_domain_delegation_to_vanity(self.account_id, domain, server_names)
+    }
+
+    /// De-delegate from vanity name servers
+    pub fn change_domain_delegation_from_vanity(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service
+            .change_domain_delegation_from_vanity(self.account_id, domain)
+    }
+
+    /// Retrieve the domain WHOIS privacy
+    pub fn get_whois_privacy(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<WhoisPrivacy>> {
+        self.service.get_whois_privacy(self.account_id, domain)
+    }
+
+    /// Enable WHOIS privacy
+    pub fn enable_whois_privacy(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<WhoisPrivacy>> {
+        self.service.enable_whois_privacy(self.account_id, domain)
+    }
+
+    /// Disable WHOIS privacy
+    pub fn disable_whois_privacy(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<WhoisPrivacy>> {
+        self.service.disable_whois_privacy(self.account_id, domain)
+    }
+
+    /// Renew WHOIS privacy
+    pub fn renew_whois_privacy(
+        &self,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<WhoisPrivacyRenewal>> {
+        self.service.renew_whois_privacy(self.account_id, domain)
+    }
+}
+
+/// The `certificates` service scoped to an account, see `Certificates`
+#[derive(Clone)]
+pub struct AccountCertificates<C = Client> {
+    service: Certificates<C>,
+    account_id: AccountId,
+}
+
+impl<C: ApiClient> AccountCertificates<C> {
+    /// List the certificates for a domain in the account.
+    pub fn list_certificates(
+        &self,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Certificate>>> {
+        self.service
+            .list_certificates(self.account_id, domain, options)
+    }
+
+    /// Get the details of a certificate
+    pub fn get_certificate(
+        &self,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+    ) -> C::Result<'_, DNSimpleResponse<Certificate>> {
+        self.service
+            .get_certificate(self.account_id, domain, certificate_id)
+    }
+
+    /// Download a certificate
+    pub fn download_certificate(
+        &self,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+    ) -> C::Result<'_, DNSimpleResponse<CertificateBundle>> {
+        self.service
+            .download_certificate(self.account_id, domain, certificate_id)
+    }
+
+    /// Get the PEM-encoded certificate private key
+    pub fn get_certificate_private_key(
+        &self,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+    ) -> C::Result<'_, DNSimpleResponse<CertificatePrivateKey>> {
+        self.service
+            .get_certificate_private_key(self.account_id, domain, certificate_id)
+    }
+
+    /// Purchase a Let’s Encrypt certificate with DNSimple.
+    pub fn purchase_letsencrypt_certificate(
+        &self,
+        domain: impl Into<DomainRef>,
+        payload: LetsEncryptPurchasePayload,
+    ) -> C::Result<'_, DNSimpleResponse<LetsEncryptPurchase>> {
+        self.service
+            .purchase_letsencrypt_certificate(self.account_id, domain, payload)
+    }
+
+    /// Issue a Let’s Encrypt certificate for a domain in the account
+    pub fn issue_letsencrypt_certificate(
+        &self,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+    ) -> C::Result<'_, DNSimpleResponse<Certificate>> {
+        self.service
+            .issue_letsencrypt_certificate(self.account_id, domain, certificate_id)
+    }
+
+    /// Purchase a Let’s Encrypt certificate renewal
+    pub fn purchase_letsencrypt_certificate_renewal(
+        &self,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+        payload: LetsEncryptPurchaseRenewalPayload,
+    ) -> C::Result<'_, DNSimpleResponse<LetsEncryptPurchaseRenewal>> {
+        self.service.purchase_letsencrypt_certificate_renewal(
+            self.account_id,
+            domain,
+            certificate_id,
+            payload,
+        )
+    }
+
+    /// Issue a Let’s Encrypt certificate renewal for a domain in the account
+    pub fn issue_letsencrypt_certificate_renewal(
+        &self,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+        certificate_renewal_id: impl Into<CertificateRenewalId>,
+    ) -> C::Result<'_, DNSimpleResponse<Certificate>> {
+        self.service.issue_letsencrypt_certificate_renewal(
+            self.account_id,
+            domain,
+            certificate_id,
+            certificate_renewal_id,
+        )
+    }
+}
+
+impl AccountCertificates<Client> {
+    /// Lists all the certificates, fetching the pages lazily (see `list_certificates`)
+    pub fn list_certificates_iter(
+        &self,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Certificate> {
+        self.service
+            .list_certificates_iter(self.account_id, domain, options)
+    }
+}
+
+/// The `contacts` service scoped to an account, see `Contacts`
+#[derive(Clone)]
+pub struct AccountContacts<C = Client> {
+    service: Contacts<C>,
+    account_id: AccountId,
+}
+
+impl<C: ApiClient> AccountContacts<C> {
+    /// Lists the contacts in the account.
+    pub fn list_contacts(
+        &self,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Contact>>> {
+        self.service.list_contacts(self.account_id, options)
+    }
+
+    /// Create a contact in the account.
+    pub fn create_contact(
+        &self,
+        payload: ContactPayload,
+    ) -> C::Result<'_, DNSimpleResponse<Contact>> {
+        self.service.create_contact(self.account_id, payload)
+    }
+
+    /// Retrieve a contact
+    pub fn get_contact(
+        &self,
+        contact: impl Into<ContactId>,
+    ) -> C::Result<'_, DNSimpleResponse<Contact>> {
+        self.service.get_contact(self.account_id, contact)
+    }
+
+    /// Update a contact
+    pub fn update_contact(
+        &self,
+        contact: impl Into<ContactId>,
+        payload: ContactPayload,
+    ) -> C::Result<'_, DNSimpleResponse<Contact>> {
+        self.service
+            .update_contact(self.account_id, contact, payload)
+    }
+
+    /// Delete a contact
+    pub fn delete_contact(
+        &self,
+        contact: impl Into<ContactId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.delete_contact(self.account_id, contact)
+    }
+}
+
+impl AccountContacts<Client> {
+    /// Lists all the contacts, fetching the pages lazily (see `list_contacts`)
+    pub fn list_contacts_iter(&self, options: Option<RequestOptions>) -> Paginator<Contact> {
+        self.service.list_contacts_iter(self.account_id, options)
+    }
+}
+
+/// The `templates` service scoped to an account, see `Templates`
+#[derive(Clone)]
+pub struct AccountTemplates<C = Client> {
+    service: Templates<C>,
+    account_id: AccountId,
+}
+
+impl<C: ApiClient> AccountTemplates<C> {
+    /// List templates in the account.
+    pub fn list_templates(
+        &self,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Template>>> {
+        self.service.list_templates(self.account_id, options)
+    }
+
+    /// Create a template in the account
+    pub fn create_template(
+        &self,
+        payload: TemplatePayload,
+    ) -> C::Result<'_, DNSimpleResponse<Template>> {
+        self.service.create_template(self.account_id, payload)
+    }
+
+    /// Retrieve a template in the account
+    pub fn get_template(
+        &self,
+        template: impl Into<TemplateRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Template>> {
+        self.service.get_template(self.account_id, template)
+    }
+
+    /// Update a template in the account
+    pub fn update_template(
+        &self,
+        template: impl Into<TemplateRef>,
+        payload: TemplatePayload,
+    ) -> C::Result<'_, DNSimpleResponse<Template>> {
+        self.service
+            .update_template(self.account_id, template, payload)
+    }
+
+    /// Deletes a template from the account
+    pub fn delete_template(
+        &self,
+        template: impl Into<TemplateRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.delete_template(self.account_id, template)
+    }
+
+    /// List template records
+    pub fn list_template_records(
+        &self,
+        template: impl Into<TemplateRef>,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<TemplateRecord>>> {
+        self.service
+            .list_template_records(self.account_id, template, options)
+    }
+
+    /// Create a template record
+    pub fn create_template_record(
+        &self,
+        template: impl Into<TemplateRef>,
+        payload: TemplateRecordPayload,
+    ) -> C::Result<'_, DNSimpleResponse<TemplateRecord>> {
+        self.service
+            .create_template_record(self.account_id, template, payload)
+    }
+
+    /// Retrieve a template record
+    pub fn get_template_record(
+        &self,
+        template: impl Into<TemplateRef>,
+        record: impl Into<TemplateRecordId>,
+    ) -> C::Result<'_, DNSimpleResponse<TemplateRecord>> {
+        self.service
+            .get_template_record(self.account_id, template, record)
+    }
+
+    /// Delete a template record
+    pub fn delete_template_record(
+        &self,
+        template: impl Into<TemplateRef>,
+        record: impl Into<TemplateRecordId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service
+            .delete_template_record(self.account_id, template, record)
+    }
+
+    /// Applies a template to a domain.
+    pub fn apply_template(
+        &self,
+        domain: impl Into<DomainRef>,
+        template: impl Into<TemplateRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service
+            .apply_template(self.account_id, domain, template)
+    }
+}
+
+impl AccountTemplates<Client> {
+    /// Lists all the templates, fetching the pages lazily (see `list_templates`)
+    pub fn list_templates_iter(&self, options: Option<RequestOptions>) -> Paginator<Template> {
+        self.service.list_templates_iter(self.account_id, options)
+    }
+
+    /// Lists all the template records, fetching the pages lazily (see `list_template_records`)
+    pub fn list_template_records_iter(
+        &self,
+        template: impl Into<TemplateRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<TemplateRecord> {
+        self.service
+            .list_template_records_iter(self.account_id, template, options)
+    }
+}
+
+/// The `webhooks` service scoped to an account, see `Webhooks`
+#[derive(Clone)]
+pub struct AccountWebhooks<C = Client> {
+    service: Webhooks<C>,
+    account_id: AccountId,
+}
+
+impl<C: ApiClient> AccountWebhooks<C> {
+    /// List webhooks in the account.
+    pub fn list_webhooks(
+        &self,
+        options: Option<RequestOptions>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Webhook>>> {
+        self.service.list_webhooks(self.account_id, options)
+    }
+
+    /// Create a webhook in the account
+    pub fn create_webhook(&self, url: String) -> C::Result<'_, DNSimpleResponse<Webhook>> {
+        self.service.create_webhook(self.account_id, url)
+    }
+
+    /// Retrieve a webhook
+    pub fn get_webhook(
+        &self,
+        webhook: impl Into<WebhookId>,
+    ) -> C::Result<'_, DNSimpleResponse<Webhook>> {
+        self.service.get_webhook(self.account_id, webhook)
+    }
+
+    /// Deletes a webhook
+    pub fn delete_webhook(
+        &self,
+        webhook: impl Into<WebhookId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        self.service.delete_webhook(self.account_id, webhook)
+    }
+}
+
+impl AccountWebhooks<Client> {
+    /// Lists all the webhooks, fetching the pages lazily (see `list_webhooks`)
+    pub fn list_webhooks_iter(&self, options: Option<RequestOptions>) -> Paginator<Webhook> {
+        self.service.list_webhooks_iter(self.account_id, options)
+    }
+}
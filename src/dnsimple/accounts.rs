@@ -1,6 +1,5 @@
 use crate::dnsimple::identity::Account;
-use crate::dnsimple::{Client, DNSimpleResponse, Endpoint};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::{ApiClient, Client, DNSimpleResponse, Endpoint};
 
 struct AccountsEndpoint;
 
@@ -11,11 +10,12 @@ impl Endpoint for AccountsEndpoint {
 /// The Accounts Service handles the accounts endpoint of the DNSimple API.
 ///
 /// See [API Documentation: accounts](https://developer.dnsimple.com/v2/accounts/)
-pub struct Accounts<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Accounts<C = Client> {
+    pub client: C,
 }
 
-impl Accounts<'_> {
+impl<C: ApiClient> Accounts<C> {
     /// Lists the accounts the current authenticated entity has access to.
     ///
     /// # Examples
@@ -28,7 +28,7 @@ impl Accounts<'_> {
     /// let accounts = response.data.unwrap();
     /// let first_account = accounts.first().unwrap();
     /// ```
-    pub fn list_accounts(&self) -> Result<DNSimpleResponse<Vec<Account>>, DNSimpleError> {
+    pub fn list_accounts(&self) -> C::Result<'_, DNSimpleResponse<Vec<Account>>> {
         self.client.get::<AccountsEndpoint>("/accounts", None)
     }
 }
@@ -0,0 +1,600 @@
+use crate::dnsimple::account_scope::{account_id_of, AccountScope};
+use crate::dnsimple::accounts::Accounts;
+use crate::dnsimple::certificates::Certificates;
+use crate::dnsimple::contacts::Contacts;
+use crate::dnsimple::credentials::{Credentials, CredentialsProvider};
+use crate::dnsimple::domains::Domains;
+use crate::dnsimple::identity::Identity;
+use crate::dnsimple::ids::AccountId;
+use crate::dnsimple::oauth::OAuth;
+use crate::dnsimple::registrar::Registrar;
+use crate::dnsimple::services::Services;
+use crate::dnsimple::templates::Templates;
+use crate::dnsimple::tlds::Tlds;
+use crate::dnsimple::vanity_name_servers::VanityNameServers;
+use crate::dnsimple::webhooks::Webhooks;
+use crate::dnsimple::zones::Zones;
+use crate::dnsimple::{
+    build_response, payload_to_json, query_parameters, ApiClient, DNSimpleEmptyResponse,
+    DNSimpleResponse, Endpoint, RateLimit, RateLimiter, RequestOptions, RetryPolicy, API_VERSION,
+    DEFAULT_BASE_URL, DEFAULT_SANDBOX_URL, DEFAULT_USER_AGENT, RATE_LIMIT_LIMIT,
+    RATE_LIMIT_REMAINING, RATE_LIMIT_RESET, VERSION,
+};
+use crate::errors::{DNSimpleError, ErrorContext, TransportErrorKind, REQUEST_ID};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use reqwest::{Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// A boxed future, returned by every call made through the `AsyncClient`.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Represents the asynchronous Rust client for the DNSimple API V2
+///
+/// The asynchronous client exposes the same services as the blocking `Client`,
+/// sharing the same models and errors, but every call returns a future that
+/// has to be awaited (within a [tokio](https://tokio.rs) runtime).
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{AsyncClient, new_async_client};
+///
+/// # async fn run() {
+/// let client = new_async_client(true, String::from("AUTH_TOKEN"));
+/// let identity = client.identity().whoami().await.unwrap().data.unwrap();
+///
+/// let account = identity.account.unwrap();
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AsyncClient {
+    pub(crate) base_url: String,
+    pub(crate) user_agent: String,
+    pub(crate) credentials: Arc<dyn CredentialsProvider>,
+    pub(crate) account_id: Option<AccountId>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) http: reqwest::Client,
+}
+
+/// Helper function to create a new asynchronous client
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{AsyncClient, new_async_client};
+///
+/// let client = new_async_client(true, String::from("AUTH_TOKEN"));
+/// ```
+///
+/// # Arguments
+///
+/// `sandbox`: `true` if you want to run in the sandbox environment, otherwise `false`
+/// `token`: the bearer authentication token
+pub fn new_async_client(sandbox: bool, token: String) -> AsyncClient {
+    let mut url = DEFAULT_BASE_URL;
+    if sandbox {
+        url = DEFAULT_SANDBOX_URL;
+    }
+
+    AsyncClient {
+        base_url: String::from(url),
+        user_agent: DEFAULT_USER_AGENT.to_owned() + VERSION,
+        credentials: Arc::new(Credentials::Bearer(token)),
+        account_id: None,
+        retry_policy: RetryPolicy::none(),
+        rate_limiter: None,
+        http: reqwest::Client::new(),
+    }
+}
+
+impl AsyncClient {
+    /// Returns a handle on the given account, whose services don't take the `account_id`
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    pub fn account(&self, account_id: impl Into<AccountId>) -> AccountScope<AsyncClient> {
+        AccountScope::new(self.clone(), account_id)
+    }
+
+    /// Returns a handle on the configured account, or on the account of the token (found with
+    /// a `whoami`) when no account id was configured
+    ///
+    /// Fails with `DNSimpleError::Configuration` for a user token.
+    pub async fn default_account(&self) -> Result<AccountScope<AsyncClient>, DNSimpleError> {
+        if let Some(account_id) = self.account_id {
+            return Ok(AccountScope::new(self.clone(), account_id));
+        }
+
+        let whoami = self.identity().whoami().await?;
+
+        Ok(AccountScope::new(self.clone(), account_id_of(whoami.data)?))
+    }
+
+    /// The account id the client was configured with, if any.
+    pub fn account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    ///Returns the `accounts` service attached to this client
+    pub fn accounts(&self) -> Accounts<AsyncClient> {
+        Accounts {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `contacts` service attached to this client
+    pub fn contacts(&self) -> Contacts<AsyncClient> {
+        Contacts {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `certificates` service attached to this client
+    pub fn certificates(&self) -> Certificates<AsyncClient> {
+        Certificates {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `domains` service attached to this client
+    pub fn domains(&self) -> Domains<AsyncClient> {
+        Domains {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `identity` service attached to this client
+    pub fn identity(&self) -> Identity<AsyncClient> {
+        Identity {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `oauth` service attached to this client
+    pub fn oauth(&self) -> OAuth<AsyncClient> {
+        OAuth {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `registrar` service attached to this client
+    pub fn registrar(&self) -> Registrar<AsyncClient> {
+        Registrar {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `services` service attached to this client
+    pub fn services(&self) -> Services<AsyncClient> {
+        Services {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `templates` service attached to this client
+    pub fn templates(&self) -> Templates<AsyncClient> {
+        Templates {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `tlds` service attached to this client
+    pub fn tlds(&self) -> Tlds<AsyncClient> {
+        Tlds {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `vanity_name_servers` service attached to this client
+    pub fn vanity_name_servers(&self) -> VanityNameServers<AsyncClient> {
+        VanityNameServers {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `webhooks` service attached to this client
+    pub fn webhooks(&self) -> Webhooks<AsyncClient> {
+        Webhooks {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `zones` service attached to this client
+    pub fn zones(&self) -> Zones<AsyncClient> {
+        Zones {
+            client: self.clone(),
+        }
+    }
+
+    /// Convenience function to change the base url in runtime (used internally for
+    /// testing).
+    ///
+    /// # Arguments
+    ///
+    /// `url`: The url we want to change the base url to.
+    pub fn set_base_url(&mut self, url: &str) {
+        self.base_url = String::from(url);
+    }
+
+    /// Changes the `RetryPolicy` used to re-issue requests failing with a transient error.
+    ///
+    /// # Arguments
+    ///
+    /// `policy`: The policy to use from now on.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Holds the requests back (or fails them) before they exceed the rate limit.
+    ///
+    /// # Arguments
+    ///
+    /// `limiter`: The limiter to use from now on (it can be shared with other clients).
+    pub fn set_rate_limiter(&mut self, limiter: RateLimiter) {
+        self.rate_limiter = Some(limiter);
+    }
+
+    /// Returns the current url (including the `API_VERSION` as part of the path).
+    pub fn versioned_url(&self) -> String {
+        let mut url = String::from(&self.base_url);
+        url.push('/');
+        url.push_str(API_VERSION);
+        url
+    }
+
+    /// Sends a GET request to the DNSimple API
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `options`: optionally a `RequestOptions` with things like pagination, filtering and sorting
+    pub async fn get<E: Endpoint>(
+        &self,
+        path: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.call(self.build_get_request(path, options)).await
+    }
+
+    /// Sends a POST request to the DNSimple API
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `data`: the json payload to be sent to the server
+    pub async fn post<E: Endpoint>(
+        &self,
+        path: &str,
+        data: Value,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.call(self.build_request(Method::POST, path).json(&data))
+            .await
+    }
+
+    /// Sends a POST request to the DNSimple API without any payload
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    pub async fn empty_post(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        self.call_empty(self.build_request(Method::POST, path))
+            .await
+    }
+
+    /// Sends a PUT request to the DNSimple API
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `data`: the json payload to be sent to the server
+    pub async fn put<E: Endpoint>(
+        &self,
+        path: &str,
+        data: Value,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.call(self.build_request(Method::PUT, path).json(&data))
+            .await
+    }
+
+    /// Sends a PUT request to the DNSimple API without any payload
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    pub async fn empty_put(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        self.call_empty(self.build_request(Method::PUT, path)).await
+    }
+
+    /// Sends a PATCH request to the DNSimple API
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `data`: the json payload to be sent to the server
+    pub async fn patch<E: Endpoint>(
+        &self,
+        path: &str,
+        data: Value,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.call(self.build_request(Method::PATCH, path).json(&data))
+            .await
+    }
+
+    /// Sends a DELETE request to the DNSimple API
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    pub async fn delete(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        self.call_empty(self.build_request(Method::DELETE, path))
+            .await
+    }
+
+    /// Sends a DELETE request to the DNSimple API returning a response containing a `DNSimpleResponse`
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    pub async fn delete_with_response<E: Endpoint>(
+        &self,
+        path: &str,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.call(self.build_request(Method::DELETE, path)).await
+    }
+
+    async fn call<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<DNSimpleResponse<T>, DNSimpleError> {
+        let response = self.send(request).await?;
+
+        let rate_limit = rate_limit_of(response.headers());
+        let status = response.status().as_u16();
+
+        let json = response
+            .json::<Value>()
+            .await
+            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
+
+        build_response(rate_limit, status, json)
+    }
+
+    async fn call_empty(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let response = self.send(request).await?;
+
+        Ok(DNSimpleEmptyResponse {
+            rate_limit: rate_limit_of(response.headers()),
+            status: response.status().as_u16(),
+        })
+    }
+
+    async fn unauthenticated_call<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<T, DNSimpleError> {
+        let request = request.build().map_err(parse_transport)?;
+        let method = request.method().to_string();
+        let path = request.url().path().to_string();
+        let response = self.http.execute(request).await.map_err(parse_transport)?;
+
+        if !response.status().is_success() {
+            return Err(error_of(method, path, response).await);
+        }
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))
+    }
+
+    /// Sends the request, retrying it as long as the `RetryPolicy` of the client allows it.
+    async fn send(&self, request: RequestBuilder) -> Result<Response, DNSimpleError> {
+        let request = request.build().map_err(parse_transport)?;
+        let mut attempt = 1;
+
+        loop {
+            if let Some(ref limiter) = self.rate_limiter {
+                while let Some(wait) = limiter.acquire()? {
+                    tokio::time::sleep(wait).await;
+                }
+            }
+
+            let mut retry = request.try_clone().ok_or_else(|| {
+                DNSimpleError::Transport(
+                    String::from("the request cannot be sent again"),
+                    TransportErrorKind::Other,
+                )
+            })?;
+            retry
+                .headers_mut()
+                .insert(AUTHORIZATION, self.authorization()?);
+            let response = self.http.execute(retry).await.map_err(parse_transport)?;
+            let status = response.status();
+
+            if let Some(ref limiter) = self.rate_limiter {
+                limiter.update(rate_limit_of(response.headers()));
+            }
+
+            if !status.is_client_error() && !status.is_server_error() {
+                return Ok(response);
+            }
+
+            let delay = self.retry_policy.delay(
+                request.method().as_str(),
+                attempt,
+                status.as_u16(),
+                header(response.headers(), RATE_LIMIT_RESET),
+            );
+
+            match delay {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => {
+                    let method = request.method().to_string();
+                    let path = request.url().path().to_string();
+                    return Err(error_of(method, path, response).await);
+                }
+            }
+
+            attempt += 1;
+        }
+    }
+
+    fn build_get_request(&self, path: &str, options: Option<RequestOptions>) -> RequestBuilder {
+        self.build_request(Method::GET, path)
+            .query(&query_parameters(options))
+    }
+
+    fn build_request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http
+            .request(method, self.url(path))
+            .header("User-Agent", &self.user_agent)
+            .header("Accept", "application/json")
+    }
+
+    fn authorization(&self) -> Result<HeaderValue, DNSimpleError> {
+        let credentials = self.credentials.credentials()?;
+        let mut value = HeaderValue::from_str(&credentials.authorization())
+            .map_err(|e| DNSimpleError::Configuration(format!("invalid credentials: {}", e)))?;
+        value.set_sensitive(true);
+        Ok(value)
+    }
+
+    fn url(&self, path: &str) -> String {
+        let mut url = self.versioned_url();
+        url.push_str(path);
+        url
+    }
+}
+
+impl ApiClient for AsyncClient {
+    type Result<'a, T: 'a> = BoxFuture<'a, Result<T, DNSimpleError>>;
+
+    fn get<E: Endpoint>(
+        &self,
+        path: &str,
+        options: Option<RequestOptions>,
+    ) -> BoxFuture<'_, Result<DNSimpleResponse<E::Output>, DNSimpleError>> {
+        Box::pin(self.call(self.build_get_request(path, options)))
+    }
+
+    fn post<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> BoxFuture<'_, Result<DNSimpleResponse<E::Output>, DNSimpleError>> {
+        let request =
+            payload_to_json(data).map(|json| self.build_request(Method::POST, path).json(&json));
+        Box::pin(async move { self.call(request?).await })
+    }
+
+    fn empty_post(
+        &self,
+        path: &str,
+    ) -> BoxFuture<'_, Result<DNSimpleEmptyResponse, DNSimpleError>> {
+        Box::pin(self.call_empty(self.build_request(Method::POST, path)))
+    }
+
+    fn put<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> BoxFuture<'_, Result<DNSimpleResponse<E::Output>, DNSimpleError>> {
+        let request =
+            payload_to_json(data).map(|json| self.build_request(Method::PUT, path).json(&json));
+        Box::pin(async move { self.call(request?).await })
+    }
+
+    fn empty_put(&self, path: &str) -> BoxFuture<'_, Result<DNSimpleEmptyResponse, DNSimpleError>> {
+        Box::pin(self.call_empty(self.build_request(Method::PUT, path)))
+    }
+
+    fn patch<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> BoxFuture<'_, Result<DNSimpleResponse<E::Output>, DNSimpleError>> {
+        let request =
+            payload_to_json(data).map(|json| self.build_request(Method::PATCH, path).json(&json));
+        Box::pin(async move { self.call(request?).await })
+    }
+
+    fn delete(&self, path: &str) -> BoxFuture<'_, Result<DNSimpleEmptyResponse, DNSimpleError>> {
+        Box::pin(self.call_empty(self.build_request(Method::DELETE, path)))
+    }
+
+    fn delete_with_response<E: Endpoint>(
+        &self,
+        path: &str,
+    ) -> BoxFuture<'_, Result<DNSimpleResponse<E::Output>, DNSimpleError>> {
+        Box::pin(self.call(self.build_request(Method::DELETE, path)))
+    }
+
+    fn unauthenticated_post<T: DeserializeOwned + 'static>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> BoxFuture<'_, Result<T, DNSimpleError>> {
+        let request = serde_json::to_value(data)
+            .map(|json| self.http.post(self.url(path)).json(&json))
+            .map_err(|e| DNSimpleError::Deserialization(e.to_string()));
+        Box::pin(async move { self.unauthenticated_call(request?).await })
+    }
+
+    fn fail<T: 'static>(&self, error: DNSimpleError) -> BoxFuture<'_, Result<T, DNSimpleError>> {
+        Box::pin(async move { Err(error) })
+    }
+}
+
+/// Builds the error out of a response the API answered with an error status.
+async fn error_of(method: String, path: String, response: Response) -> DNSimpleError {
+    let status = response.status();
+    let context = ErrorContext {
+        status: status.as_u16(),
+        request_id: header(response.headers(), REQUEST_ID).map(String::from),
+        method,
+        path,
+        rate_limit: rate_limit_of(response.headers()),
+        body: response.text().await.unwrap_or_default(),
+    };
+    DNSimpleError::from_context(context, status.canonical_reason().unwrap_or_default())
+}
+
+fn header<'h>(headers: &'h HeaderMap, name: &str) -> Option<&'h str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn rate_limit_of(headers: &HeaderMap) -> Option<RateLimit> {
+    RateLimit::from_headers(
+        header(headers, RATE_LIMIT_LIMIT),
+        header(headers, RATE_LIMIT_REMAINING),
+        header(headers, RATE_LIMIT_RESET),
+    )
+}
+
+fn parse_transport(error: reqwest::Error) -> DNSimpleError {
+    let kind = if error.is_timeout() {
+        TransportErrorKind::Timeout
+    } else if error.is_connect() {
+        TransportErrorKind::ConnectionFailed
+    } else if error.is_builder() {
+        TransportErrorKind::InvalidUrl
+    } else if error.is_request() || error.is_body() {
+        TransportErrorKind::Io
+    } else if error.is_redirect() {
+        TransportErrorKind::BadResponse
+    } else {
+        TransportErrorKind::Other
+    };
+
+    DNSimpleError::Transport(error.to_string(), kind)
+}
@@ -0,0 +1,235 @@
+use crate::dnsimple::{Client, RetryPolicy};
+use crate::errors::DNSimpleError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::SystemTime;
+
+/// The result of one operation run by a `BulkExecutor`
+#[derive(Debug)]
+pub struct BulkOutcome<T> {
+    /// The position of the operation in the list given to `run`.
+    pub index: usize,
+    /// What the operation returned the last time it was run.
+    pub result: Result<T, DNSimpleError>,
+    /// The number of times the operation was run.
+    pub attempts: u32,
+}
+
+impl<T> BulkOutcome<T> {
+    /// Whether the operation succeeded.
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// The outcome of every operation run by a `BulkExecutor`, in the order of the operations
+#[derive(Debug)]
+pub struct BulkReport<T> {
+    /// The outcomes, `outcomes[i]` being the one of the i-th operation.
+    pub outcomes: Vec<BulkOutcome<T>>,
+}
+
+impl<T> BulkReport<T> {
+    /// The outcomes of the operations that succeeded.
+    pub fn succeeded(&self) -> impl Iterator<Item = &BulkOutcome<T>> {
+        self.outcomes.iter().filter(|outcome| outcome.is_success())
+    }
+
+    /// The outcomes of the operations that failed.
+    pub fn failed(&self) -> impl Iterator<Item = &BulkOutcome<T>> {
+        self.outcomes.iter().filter(|outcome| !outcome.is_success())
+    }
+
+    /// Whether all the operations succeeded.
+    pub fn is_success(&self) -> bool {
+        self.outcomes.iter().all(BulkOutcome::is_success)
+    }
+}
+
+/// Runs a list of operations with a bounded number of workers
+///
+/// Every operation is run, whatever happens to the others, and its result ends up in the
+/// `BulkReport`. Operations failing with a transient error are run again as described by the
+/// `RetryPolicy` of the executor; when the API throttles one of them, all the workers wait for
+/// the rate limit to reset. Give the `Client` a `RateLimiter` to stay under the rate limit in
+/// the first place.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+/// use dnsimple::dnsimple::zones_records::ZoneRecordPayload;
+///
+/// let client = new_client(true, String::from("AUTH_TOKEN"));
+/// let hosts = vec!["www", "api", "mail"];
+///
+/// let report = client.bulk().workers(4).run(hosts.iter().map(|host| {
+///     move |client: &dnsimple::dnsimple::Client| {
+///         let payload = ZoneRecordPayload {
+///             name: String::from(*host),
+///             record_type: String::from("A"),
+///             content: String::from("192.0.2.1"),
+///             ttl: None,
+///             priority: None,
+///             regions: None,
+///         };
+///         client.zones().create_zone_record(1234, "example.com", payload)
+///     }
+/// }));
+///
+/// for outcome in report.failed() {
+///     eprintln!("{} failed after {} attempts", hosts[outcome.index], outcome.attempts);
+/// }
+/// ```
+pub struct BulkExecutor<'a> {
+    client: &'a Client,
+    workers: usize,
+    retry_policy: RetryPolicy,
+}
+
+impl<'a> BulkExecutor<'a> {
+    /// The number of operations run at the same time (4 by default, at least 1).
+    pub fn workers(mut self, workers: usize) -> BulkExecutor<'a> {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// How the operations failing with a transient error are run again (by default up to 3
+    /// times, with the defaults of `RetryPolicy`).
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> BulkExecutor<'a> {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Runs the operations and reports their outcomes
+    ///
+    /// An operation is given the client and may be run several times.
+    pub fn run<T, F, I>(&self, operations: I) -> BulkReport<T>
+    where
+        I: IntoIterator<Item = F>,
+        F: Fn(&Client) -> Result<T, DNSimpleError> + Send + Sync,
+        T: Send,
+    {
+        let operations: Vec<F> = operations.into_iter().collect();
+        let outcomes: Mutex<Vec<Option<BulkOutcome<T>>>> =
+            Mutex::new((0..operations.len()).map(|_| None).collect());
+        let next = AtomicUsize::new(0);
+        let pause = Pause::default();
+
+        thread::scope(|scope| {
+            for _ in 0..self.workers.min(operations.len()) {
+                scope.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::SeqCst);
+                    let operation = match operations.get(index) {
+                        Some(operation) => operation,
+                        None => break,
+                    };
+
+                    let outcome = self.execute(index, operation, &pause);
+                    lock(&outcomes)[index] = Some(outcome);
+                });
+            }
+        });
+
+        BulkReport {
+            outcomes: outcomes
+                .into_inner()
+                .unwrap_or_else(PoisonError::into_inner)
+                .into_iter()
+                .flatten()
+                .collect(),
+        }
+    }
+
+    fn execute<T, F>(&self, index: usize, operation: &F, pause: &Pause) -> BulkOutcome<T>
+    where
+        F: Fn(&Client) -> Result<T, DNSimpleError>,
+    {
+        let mut attempts = 0;
+
+        loop {
+            pause.wait();
+            attempts += 1;
+
+            let error = match operation(self.client) {
+                Ok(value) => {
+                    return BulkOutcome {
+                        index,
+                        result: Ok(value),
+                        attempts,
+                    }
+                }
+                Err(error) => error,
+            };
+
+            match self.retry_policy.operation_delay(attempts, &error) {
+                Some(wait) if error.retry_after().is_some() => {
+                    pause.until(SystemTime::now() + wait)
+                }
+                Some(wait) => thread::sleep(wait),
+                None => {
+                    return BulkOutcome {
+                        index,
+                        result: Err(error),
+                        attempts,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The time until which all the workers wait, once the API throttled one of them.
+#[derive(Default)]
+struct Pause {
+    until: Mutex<Option<SystemTime>>,
+}
+
+impl Pause {
+    fn until(&self, time: SystemTime) {
+        let mut until = lock(&self.until);
+
+        if until.map_or(true, |current| current < time) {
+            *until = Some(time);
+        }
+    }
+
+    fn wait(&self) {
+        let until = *lock(&self.until);
+
+        if let Some(wait) = until.and_then(|time| time.duration_since(SystemTime::now()).ok()) {
+            thread::sleep(wait);
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Client {
+    /// Returns a `BulkExecutor` running operations with this client
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::{new_client, Client};
+    ///
+    /// let client = new_client(true, String::from("AUTH_TOKEN"));
+    /// let ids = vec![1, 2, 3];
+    ///
+    /// let report = client.bulk().workers(2).run(ids.iter().map(|id| {
+    ///     move |client: &Client| client.zones().delete_zone_record(1234, "example.com", *id)
+    /// }));
+    ///
+    /// assert!(report.is_success());
+    /// ```
+    pub fn bulk(&self) -> BulkExecutor<'_> {
+        BulkExecutor {
+            client: self,
+            workers: 4,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
@@ -0,0 +1,363 @@
+use crate::dnsimple::{HttpRequest, HttpResponse, Transport};
+use crate::errors::{DNSimpleError, TransportErrorKind};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Replaces the token in the recorded interactions.
+const FILTERED: &str = "[FILTERED]";
+
+/// The JSON fields whose values are secrets, filtered out of the recorded bodies (the OAuth
+/// exchange sends its secrets in the bodies, not in an `Authorization` header).
+const SECRET_FIELDS: [&str; 3] = ["client_secret", "access_token", "code"];
+
+/// A request/response pair stored in a cassette
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Interaction {
+    /// The request sent.
+    pub request: RecordedRequest,
+    /// The response received.
+    pub response: RecordedResponse,
+}
+
+/// What is kept of a request in a cassette (the headers are never recorded)
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RecordedRequest {
+    /// The HTTP method.
+    pub method: String,
+    /// The path of the url (i.e. `/v2/1010/zones`), so a cassette can be replayed against any host.
+    pub path: String,
+    /// The query parameters.
+    pub query: Vec<(String, String)>,
+    /// The JSON payload, if any.
+    pub body: Option<Value>,
+}
+
+impl RecordedRequest {
+    /// Whether the request is the same as the other one, whatever the order of their query
+    /// parameters.
+    pub fn matches(&self, other: &RecordedRequest) -> bool {
+        let sorted = |query: &[(String, String)]| {
+            let mut query = query.to_vec();
+            query.sort();
+            query
+        };
+
+        self.method == other.method
+            && self.path == other.path
+            && self.body == other.body
+            && sorted(&self.query) == sorted(&other.query)
+    }
+}
+
+/// A response stored in a cassette
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RecordedResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The reason phrase of the status.
+    pub status_text: String,
+    /// The headers of the response.
+    pub headers: Vec<(String, String)>,
+    /// The raw body of the response.
+    pub body: String,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+/// A `Transport` recording the interactions with the API into a cassette file
+///
+/// The requests are sent through another transport (`UreqTransport` usually) and every
+/// request/response pair is appended to the cassette, which is written after each request.
+/// The request headers are not recorded and the token is replaced by `[FILTERED]` wherever
+/// it appears, as are the values of the `client_secret`, `access_token` and `code` fields of
+/// the JSON bodies.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{Client, RecordingTransport, UreqTransport};
+///
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .sandbox(true)
+///     .transport(RecordingTransport::new(UreqTransport::default(), "tests/cassettes/zones.json"))
+///     .build()
+///     .unwrap();
+///
+/// client.zones().list_zones(1010, None).unwrap();
+/// ```
+pub struct RecordingTransport {
+    inner: Box<dyn Transport>,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl RecordingTransport {
+    /// Creates a transport recording into a new cassette at the given path
+    ///
+    /// # Arguments
+    ///
+    /// `inner`: the transport actually sending the requests
+    /// `path`: the cassette file, overwritten once the first request is sent
+    pub fn new(inner: impl Transport + 'static, path: impl AsRef<Path>) -> RecordingTransport {
+        RecordingTransport {
+            inner: Box::new(inner),
+            path: path.as_ref().to_path_buf(),
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+
+    /// The interactions recorded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        lock(&self.cassette).interactions.clone()
+    }
+
+    fn save(&self, cassette: &Cassette) -> Result<(), DNSimpleError> {
+        let json = serde_json::to_string_pretty(cassette)
+            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
+
+        fs::write(&self.path, json).map_err(|e| {
+            DNSimpleError::Transport(
+                format!("cannot write the cassette {}: {}", self.path.display(), e),
+                TransportErrorKind::Cassette,
+            )
+        })
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+        let response = self.inner.send(request)?;
+        let scrub = Scrubber::for_request(request);
+
+        let interaction = Interaction {
+            request: scrub.request(recorded_request(request)),
+            response: scrub.response(RecordedResponse {
+                status: response.status,
+                status_text: response.status_text.clone(),
+                headers: response.headers.clone(),
+                body: response.body.clone(),
+            }),
+        };
+
+        let mut cassette = lock(&self.cassette);
+        cassette.interactions.push(interaction);
+        self.save(&cassette)?;
+
+        Ok(response)
+    }
+}
+
+/// A `Transport` serving the interactions of a cassette, without any network access
+///
+/// A request is answered with the first interaction not replayed yet having the same method,
+/// path, query and body (or with the last one matching if they all were). Requests without
+/// any matching interaction fail with a `DNSimpleError::Transport`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{Client, ReplayTransport};
+///
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .transport(ReplayTransport::from_file("tests/cassettes/zones.json").unwrap())
+///     .build()
+///     .unwrap();
+///
+/// let zones = client.zones().list_zones(1010, None).unwrap().data.unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ReplayTransport {
+    interactions: Vec<Interaction>,
+    replayed: Mutex<Vec<bool>>,
+}
+
+impl ReplayTransport {
+    /// Loads the cassette at the given path
+    ///
+    /// Fails with `DNSimpleError::Configuration` if it cannot be read or parsed.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<ReplayTransport, DNSimpleError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| {
+            DNSimpleError::Configuration(format!(
+                "cannot read the cassette {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let cassette: Cassette = serde_json::from_str(&content).map_err(|e| {
+            DNSimpleError::Configuration(format!("invalid cassette {}: {}", path.display(), e))
+        })?;
+
+        Ok(ReplayTransport::new(cassette.interactions))
+    }
+
+    /// Serves the given interactions.
+    pub fn new(interactions: Vec<Interaction>) -> ReplayTransport {
+        let replayed = vec![false; interactions.len()];
+
+        ReplayTransport {
+            interactions,
+            replayed: Mutex::new(replayed),
+        }
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+        let recorded = Scrubber::for_request(request).request(recorded_request(request));
+        let mut replayed = lock(&self.replayed);
+
+        let matching: Vec<usize> = self
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| interaction.request.matches(&recorded))
+            .map(|(index, _)| index)
+            .collect();
+        let index = matching
+            .iter()
+            .find(|index| !replayed[**index])
+            .or_else(|| matching.last())
+            .copied()
+            .ok_or_else(|| {
+                DNSimpleError::Transport(
+                    format!(
+                        "no interaction recorded for {} {}",
+                        recorded.method, recorded.path
+                    ),
+                    TransportErrorKind::Cassette,
+                )
+            })?;
+
+        replayed[index] = true;
+        let response = &self.interactions[index].response;
+
+        Ok(HttpResponse {
+            status: response.status,
+            status_text: response.status_text.clone(),
+            headers: response.headers.clone(),
+            body: response.body.clone(),
+        })
+    }
+}
+
+/// Removes the token of a request, and the secret fields of the bodies, from what gets
+/// recorded.
+struct Scrubber {
+    token: Option<String>,
+}
+
+impl Scrubber {
+    fn for_request(request: &HttpRequest) -> Scrubber {
+        let token = request
+            .header("Authorization")
+            .and_then(|authorization| {
+                authorization
+                    .strip_prefix("Bearer ")
+                    .or_else(|| authorization.strip_prefix("Basic "))
+            })
+            .filter(|token| !token.is_empty())
+            .map(String::from);
+
+        Scrubber { token }
+    }
+
+    fn request(&self, mut request: RecordedRequest) -> RecordedRequest {
+        for (_, value) in request.query.iter_mut() {
+            *value = self.text(value);
+        }
+        request.body = request.body.map(|body| {
+            let mut body = serde_json::from_str(&self.text(&body.to_string())).unwrap_or(body);
+            filter_secret_fields(&mut body);
+            body
+        });
+        request
+    }
+
+    fn response(&self, mut response: RecordedResponse) -> RecordedResponse {
+        for (_, value) in response.headers.iter_mut() {
+            *value = self.text(value);
+        }
+        response.body = self.text(&response.body);
+        if let Ok(mut body) = serde_json::from_str::<Value>(&response.body) {
+            if filter_secret_fields(&mut body) {
+                response.body = body.to_string();
+            }
+        }
+        response
+    }
+
+    fn text(&self, text: &str) -> String {
+        match self.token {
+            Some(ref token) => text.replace(token.as_str(), FILTERED),
+            None => text.to_string(),
+        }
+    }
+}
+
+/// Replaces the string values of the `SECRET_FIELDS`, returning whether any was found.
+fn filter_secret_fields(value: &mut Value) -> bool {
+    let mut filtered = false;
+
+    match value {
+        Value::Object(fields) => {
+            for (name, value) in fields.iter_mut() {
+                if value.is_string() && SECRET_FIELDS.contains(&name.as_str()) {
+                    *value = Value::String(String::from(FILTERED));
+                    filtered = true;
+                } else {
+                    filtered |= filter_secret_fields(value);
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values.iter_mut() {
+                filtered |= filter_secret_fields(value);
+            }
+        }
+        _ => {}
+    }
+
+    filtered
+}
+
+fn recorded_request(request: &HttpRequest) -> RecordedRequest {
+    RecordedRequest {
+        method: request.method.clone(),
+        path: request.path().to_string(),
+        query: request.query.clone(),
+        body: request.body.clone(),
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::cassette::Scrubber;
+    use crate::dnsimple::HttpRequest;
+
+    #[test]
+    fn scrubs_the_token() {
+        let mut request = HttpRequest::new("GET", "https://api.dnsimple.com/v2/whoami");
+        request.headers.push((
+            String::from("Authorization"),
+            String::from("Bearer secret-token"),
+        ));
+
+        let scrubber = Scrubber::for_request(&request);
+
+        assert_eq!(
+            "{\"token\":\"[FILTERED]\"}",
+            scrubber.text("{\"token\":\"secret-token\"}")
+        );
+    }
+}
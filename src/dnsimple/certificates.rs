@@ -1,5 +1,9 @@
-use crate::dnsimple::{Client, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, CertificateId, CertificateRenewalId, DomainRef};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{optional_date, optional_timestamp, timestamp, Date, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleResponse, Endpoint, Paginate, RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -30,13 +34,17 @@ pub struct Certificate {
     /// The Certificate Authority (CA) that issued the certificate.
     pub authority_identifier: String,
     /// When the certificate was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the certificate was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
     /// The timestamp when the certificate will expire.
-    pub expires_at: Option<String>,
+    #[serde(default, with = "optional_timestamp")]
+    pub expires_at: Option<Timestamp>,
     /// The day when the certificate will expire.
-    pub expires_on: Option<String>,
+    #[serde(default, with = "optional_date")]
+    pub expires_on: Option<Date>,
 }
 
 /// Represents the certificate bundle when downloading a certificate
@@ -69,9 +77,11 @@ pub struct LetsEncryptPurchase {
     /// True if the certificate will auto renew
     pub auto_renew: bool,
     /// When the purchase was created
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the purchase was last updated
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// A renewal for a Let's Encrypt Purchase
@@ -88,9 +98,11 @@ pub struct LetsEncryptPurchaseRenewal {
     /// True if the certificate will auto renew
     pub auto_renew: bool,
     /// When the renewal was created
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the renewal was last updated
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -125,6 +137,52 @@ pub struct LetsEncryptPurchaseRenewalPayload {
     pub signature_algorithm: Option<LetsEncryptSignatureAlgorithm>,
 }
 
+/// The sorting policies of `list_certificates`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertificateSort {
+    IdAsc,
+    IdDesc,
+    CommonNameAsc,
+    CommonNameDesc,
+    ExpirationAsc,
+    ExpirationDesc,
+}
+
+impl CertificateSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CertificateSort::IdAsc => "id:asc",
+            CertificateSort::IdDesc => "id:desc",
+            CertificateSort::CommonNameAsc => "common_name:asc",
+            CertificateSort::CommonNameDesc => "common_name:desc",
+            CertificateSort::ExpirationAsc => "expiration:asc",
+            CertificateSort::ExpirationDesc => "expiration:desc",
+        }
+    }
+}
+
+/// The sorting and pagination of the certificates list
+///
+/// Converts into the `RequestOptions` taken by `list_certificates` and `list_certificates_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct CertificateListOptions {
+    /// The sorting policy.
+    pub sort: Option<CertificateSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<CertificateListOptions> for RequestOptions {
+    fn from(options: CertificateListOptions) -> Self {
+        RequestOptions::typed(
+            Vec::new(),
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct ListCertificatesEndpoint;
 
 impl Endpoint for ListCertificatesEndpoint {
@@ -164,11 +222,12 @@ impl Endpoint for LetsEncryptPurchaseRenewalEndpoint {
 /// The Certificates Service handles the certificates endpoint of the DNSimple API.
 ///
 /// See [API Documentation: certificates](https://developer.dnsimple.com/v2/certificates/)
-pub struct Certificates<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Certificates<C = Client> {
+    pub client: C,
 }
 
-impl Certificates<'_> {
+impl<C: ApiClient> Certificates<C> {
     /// List the certificates for a domain in the account.
     ///
     /// # Examples
@@ -184,15 +243,17 @@ impl Certificates<'_> {
     ///
     /// `account_id`: The id of the account
     /// `domain`: The domain name or id
-    /// `options`: The `RequestOptions`.
+    /// `options`: The `RequestOptions` (i.e. a `CertificateListOptions` converted with `into()`)
     ///            - Sorting: `id`, `common_name`, `expiration`
     pub fn list_certificates(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Certificate>>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/certificates", account_id, domain);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Certificate>>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/certificates", account_id.into(), domain);
 
         self.client.get::<ListCertificatesEndpoint>(&path, options)
     }
@@ -215,13 +276,17 @@ impl Certificates<'_> {
     /// `certificate_id`: The certificate id
     pub fn get_certificate(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
-    ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+    ) -> C::Result<'_, DNSimpleResponse<Certificate>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/certificates/{}",
-            account_id, domain, certificate_id
+            account_id.into(),
+            domain,
+            certificate_id.into()
         );
 
         self.client.get::<CertificateEndpoint>(&path, None)
@@ -245,13 +310,17 @@ impl Certificates<'_> {
     /// `certificate_id`: The certificate id
     pub fn download_certificate(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
-    ) -> Result<DNSimpleResponse<CertificateBundle>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+    ) -> C::Result<'_, DNSimpleResponse<CertificateBundle>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/certificates/{}/download",
-            account_id, domain, certificate_id
+            account_id.into(),
+            domain,
+            certificate_id.into()
         );
 
         self.client.get::<CertificateDownloadEndpoint>(&path, None)
@@ -275,13 +344,17 @@ impl Certificates<'_> {
     /// `certificate_id`: The certificate id
     pub fn get_certificate_private_key(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
-    ) -> Result<DNSimpleResponse<CertificatePrivateKey>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+    ) -> C::Result<'_, DNSimpleResponse<CertificatePrivateKey>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/certificates/{}/private_key",
-            account_id, domain, certificate_id
+            account_id.into(),
+            domain,
+            certificate_id.into()
         );
 
         self.client
@@ -313,21 +386,20 @@ impl Certificates<'_> {
     /// `payload`: The `LetsEncryptPurchasePayload` containing the information to purchase the certificate
     pub fn purchase_letsencrypt_certificate(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: LetsEncryptPurchasePayload,
-    ) -> Result<DNSimpleResponse<LetsEncryptPurchase>, DNSimpleError> {
+    ) -> C::Result<'_, DNSimpleResponse<LetsEncryptPurchase>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/certificates/letsencrypt",
-            account_id, domain
+            account_id.into(),
+            domain
         );
 
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<LetsEncryptPurchaseEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+        self.client
+            .post::<LetsEncryptPurchaseEndpoint>(&path, payload)
     }
 
     /// Issue a Let’s Encrypt certificate for a domain in the account
@@ -348,13 +420,17 @@ impl Certificates<'_> {
     /// `certificate_id`: The id of the certificate to be issued
     pub fn issue_letsencrypt_certificate(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
-    ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+    ) -> C::Result<'_, DNSimpleResponse<Certificate>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/certificates/letsencrypt/{}/issue",
-            account_id, domain, certificate_id
+            account_id.into(),
+            domain,
+            certificate_id.into()
         );
 
         self.client.post::<CertificateEndpoint>(&path, Value::Null)
@@ -382,24 +458,22 @@ impl Certificates<'_> {
     /// `payload`: The `LetsEncryptPurchaseRenewalPayload` containing the information to purchase the certificate
     pub fn purchase_letsencrypt_certificate_renewal(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
         payload: LetsEncryptPurchaseRenewalPayload,
-    ) -> Result<DNSimpleResponse<LetsEncryptPurchaseRenewal>, DNSimpleError> {
+    ) -> C::Result<'_, DNSimpleResponse<LetsEncryptPurchaseRenewal>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/certificates/letsencrypt/{}/renewals",
-            account_id, domain, certificate_id
+            account_id.into(),
+            domain,
+            certificate_id.into()
         );
 
-        match serde_json::to_value(payload) {
-            Ok(json) => self
-                .client
-                .post::<LetsEncryptPurchaseRenewalEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+        self.client
+            .post::<LetsEncryptPurchaseRenewalEndpoint>(&path, payload)
     }
 
     /// Issue a Let’s Encrypt certificate for a domain in the account
@@ -420,16 +494,45 @@ impl Certificates<'_> {
     /// `certificate_renewal_id`: The certificate renewal id
     pub fn issue_letsencrypt_certificate_renewal(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
-        certificate_renewal_id: u64,
-    ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+        certificate_renewal_id: impl Into<CertificateRenewalId>,
+    ) -> C::Result<'_, DNSimpleResponse<Certificate>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/certificates/letsencrypt/{}/renewals/{}/issue",
-            account_id, domain, certificate_id, certificate_renewal_id
+            account_id.into(),
+            domain,
+            certificate_id.into(),
+            certificate_renewal_id.into()
         );
 
         self.client.post::<CertificateEndpoint>(&path, Value::Null)
     }
 }
+
+impl Certificates<Client> {
+    /// Lists all the certificates, fetching the pages lazily (see `list_certificates`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The id of the account
+    /// `domain`: The domain name or id
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_certificates_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Certificate> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+        let domain: DomainRef = domain.into();
+
+        Paginator::new(options, move |options| {
+            service.list_certificates(account_id, domain.clone(), Some(options))
+        })
+    }
+}
@@ -0,0 +1,237 @@
+use crate::dnsimple::{new_client, AccountId, Client};
+use crate::errors::DNSimpleError;
+use serde::Deserialize;
+#[cfg(feature = "config")]
+use std::collections::HashMap;
+use std::env;
+#[cfg(feature = "config")]
+use std::fs;
+#[cfg(feature = "config")]
+use std::path::Path;
+
+/// The environment variable holding the token (required by `Client::from_env`).
+pub const TOKEN_VAR: &str = "DNSIMPLE_TOKEN";
+/// The environment variable holding the default account id.
+pub const ACCOUNT_ID_VAR: &str = "DNSIMPLE_ACCOUNT_ID";
+/// The environment variable enabling the sandbox (`true` or `1`).
+pub const SANDBOX_VAR: &str = "DNSIMPLE_SANDBOX";
+/// The environment variable overriding the base url of the API.
+pub const BASE_URL_VAR: &str = "DNSIMPLE_BASE_URL";
+/// The environment variable choosing the profile read by `Client::from_config`.
+#[cfg(feature = "config")]
+pub const PROFILE_VAR: &str = "DNSIMPLE_PROFILE";
+/// The profile read by `Client::from_config` when `DNSIMPLE_PROFILE` is not set.
+#[cfg(feature = "config")]
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// The settings of a client, from the environment or a profile of a config file
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Settings {
+    token: Option<String>,
+    account_id: Option<u64>,
+    #[serde(default)]
+    sandbox: bool,
+    base_url: Option<String>,
+}
+
+#[cfg(feature = "config")]
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    profiles: HashMap<String, Settings>,
+}
+
+impl Settings {
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Settings, DNSimpleError> {
+        let account_id = match var(ACCOUNT_ID_VAR) {
+            Some(value) => Some(value.trim().parse().map_err(|_| {
+                DNSimpleError::Configuration(format!(
+                    "invalid setting `{}`: `{}` is not an account id",
+                    ACCOUNT_ID_VAR, value
+                ))
+            })?),
+            None => None,
+        };
+        let sandbox = match var(SANDBOX_VAR) {
+            Some(value) => match value.trim().to_lowercase().as_str() {
+                "true" | "1" => true,
+                "false" | "0" | "" => false,
+                _ => {
+                    return Err(DNSimpleError::Configuration(format!(
+                        "invalid setting `{}`: `{}` is not a boolean",
+                        SANDBOX_VAR, value
+                    )))
+                }
+            },
+            None => false,
+        };
+
+        Ok(Settings {
+            token: var(TOKEN_VAR),
+            account_id,
+            sandbox,
+            base_url: var(BASE_URL_VAR),
+        })
+    }
+
+    #[cfg(feature = "config")]
+    fn from_toml(content: &str, profile: &str, origin: &str) -> Result<Settings, DNSimpleError> {
+        let mut config: ConfigFile = toml::from_str(content).map_err(|e| {
+            DNSimpleError::Configuration(format!("invalid config file {}: {}", origin, e))
+        })?;
+
+        config.profiles.remove(profile).ok_or_else(|| {
+            DNSimpleError::Configuration(format!(
+                "missing profile `{}` in {} (expected a `[profiles.{}]` table)",
+                profile, origin, profile
+            ))
+        })
+    }
+
+    /// Creates the client, `missing_token` naming the setting to use in the error.
+    fn into_client(self, missing_token: impl FnOnce() -> String) -> Result<Client, DNSimpleError> {
+        let token = self
+            .token
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| {
+                DNSimpleError::Configuration(format!("missing setting {}", missing_token()))
+            })?;
+
+        let mut client = new_client(self.sandbox, token);
+        if let Some(ref url) = self.base_url {
+            client.set_base_url(url);
+        }
+        client.config_mut().account_id = self.account_id.map(AccountId);
+
+        Ok(client)
+    }
+}
+
+impl Client {
+    /// Creates a client from the `DNSIMPLE_*` environment variables
+    ///
+    /// - `DNSIMPLE_TOKEN`: the token (required)
+    /// - `DNSIMPLE_ACCOUNT_ID`: the account used by `default_account`
+    /// - `DNSIMPLE_SANDBOX`: `true` to use the sandbox environment
+    /// - `DNSIMPLE_BASE_URL`: the url of the API (takes precedence over the sandbox)
+    ///
+    /// Fails with `DNSimpleError::Configuration`, naming the setting, when the token is
+    /// missing or a value is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::Client;
+    ///
+    /// let client = Client::from_env().unwrap();
+    /// let zones = client.default_account().unwrap().zones().list_zones(None).unwrap();
+    /// ```
+    pub fn from_env() -> Result<Client, DNSimpleError> {
+        Settings::from_vars(|name| env::var(name).ok())?.into_client(|| format!("`{}`", TOKEN_VAR))
+    }
+
+    /// Creates a client from a profile of a TOML config file (behind the `config` feature)
+    ///
+    /// The profile is the one named by `DNSIMPLE_PROFILE`, `default` if it is not set.
+    /// Each profile has a `token` (required), and optionally an `account_id`, `sandbox`
+    /// and `base_url`:
+    ///
+    /// ```toml
+    /// [profiles.default]
+    /// token = "AUTH_TOKEN"
+    /// account_id = 1010
+    ///
+    /// [profiles.sandbox]
+    /// token = "SANDBOX_TOKEN"
+    /// sandbox = true
+    /// ```
+    ///
+    /// Fails with `DNSimpleError::Configuration` when the file cannot be read or parsed, or
+    /// when the profile or its token is missing.
+    ///
+    /// # Arguments
+    ///
+    /// `path`: The path of the config file
+    #[cfg(feature = "config")]
+    pub fn from_config(path: impl AsRef<Path>) -> Result<Client, DNSimpleError> {
+        let profile = env::var(PROFILE_VAR).unwrap_or_else(|_| String::from(DEFAULT_PROFILE));
+
+        Client::from_config_profile(path, &profile)
+    }
+
+    /// Creates a client from the given profile of a TOML config file (see `from_config`)
+    ///
+    /// # Arguments
+    ///
+    /// `path`: The path of the config file
+    /// `profile`: The name of the profile (i.e. `sandbox` for `[profiles.sandbox]`)
+    #[cfg(feature = "config")]
+    pub fn from_config_profile(
+        path: impl AsRef<Path>,
+        profile: &str,
+    ) -> Result<Client, DNSimpleError> {
+        let path = path.as_ref();
+        let origin = path.display().to_string();
+        let content = fs::read_to_string(path).map_err(|e| {
+            DNSimpleError::Configuration(format!("cannot read the config file {}: {}", origin, e))
+        })?;
+
+        Settings::from_toml(&content, profile, &origin)?
+            .into_client(|| format!("`token` in `[profiles.{}]` of {}", profile, origin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::config::Settings;
+    use crate::dnsimple::AccountId;
+    use crate::errors::DNSimpleError;
+    use std::collections::HashMap;
+
+    fn from_vars(vars: &[(&str, &str)]) -> Result<Settings, DNSimpleError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        Settings::from_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn reads_the_environment() -> Result<(), DNSimpleError> {
+        let client = from_vars(&[
+            ("DNSIMPLE_TOKEN", "some-token"),
+            ("DNSIMPLE_ACCOUNT_ID", "1010"),
+            ("DNSIMPLE_SANDBOX", "true"),
+        ])?
+        .into_client(String::new)?;
+
+        assert_eq!(Some(AccountId(1010)), client.account_id());
+        assert_eq!(
+            "https://api.sandbox.dnsimple.com/v2",
+            client.versioned_url()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn names_the_missing_token() {
+        let error = from_vars(&[("DNSIMPLE_SANDBOX", "1")])
+            .and_then(|settings| settings.into_client(|| String::from("`DNSIMPLE_TOKEN`")));
+
+        assert!(
+            matches!(error, Err(DNSimpleError::Configuration(ref message)) if message == "missing setting `DNSIMPLE_TOKEN`")
+        );
+    }
+
+    #[test]
+    fn rejects_an_invalid_account_id() {
+        let error = from_vars(&[("DNSIMPLE_TOKEN", "token"), ("DNSIMPLE_ACCOUNT_ID", "acme")]);
+
+        assert!(
+            matches!(error, Err(DNSimpleError::Configuration(ref message)) if message.contains("DNSIMPLE_ACCOUNT_ID"))
+        );
+    }
+}
@@ -1,5 +1,9 @@
-use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, ContactId};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
+use crate::dnsimple::{
+    ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, Paginate, RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -37,9 +41,11 @@ pub struct Contact {
     ///  The contact country (as a 2-character country code).
     pub country: String,
     /// When the contact was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the contact was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -74,6 +80,52 @@ pub struct ContactPayload {
     pub country: String,
 }
 
+/// The sorting policies of `list_contacts`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactSort {
+    IdAsc,
+    IdDesc,
+    LabelAsc,
+    LabelDesc,
+    EmailAsc,
+    EmailDesc,
+}
+
+impl ContactSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContactSort::IdAsc => "id:asc",
+            ContactSort::IdDesc => "id:desc",
+            ContactSort::LabelAsc => "label:asc",
+            ContactSort::LabelDesc => "label:desc",
+            ContactSort::EmailAsc => "email:asc",
+            ContactSort::EmailDesc => "email:desc",
+        }
+    }
+}
+
+/// The sorting and pagination of the contacts list
+///
+/// Converts into the `RequestOptions` taken by `list_contacts` and `list_contacts_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactListOptions {
+    /// The sorting policy.
+    pub sort: Option<ContactSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<ContactListOptions> for RequestOptions {
+    fn from(options: ContactListOptions) -> Self {
+        RequestOptions::typed(
+            Vec::new(),
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct ContactsEndpoint;
 
 impl Endpoint for ContactsEndpoint {
@@ -89,24 +141,25 @@ impl Endpoint for ContactEndpoint {
 /// The Contacts Service handles the contacts endpoint of the DNSimple API.
 ///
 /// See [API Documentation: contacts](https://developer.dnsimple.com/v2/contacts/)
-pub struct Contacts<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Contacts<C = Client> {
+    pub client: C,
 }
 
-impl Contacts<'_> {
+impl<C: ApiClient> Contacts<C> {
     /// Lists the contacts in the account.
     ///
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `options`: The `RequestOptions`
+    /// `options`: The `RequestOptions` (i.e. a `ContactListOptions` converted with `into()`)
     ///            - Sort: `id`, `label`, `email`
     pub fn list_contacts(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Contact>>, DNSimpleError> {
-        let path = format!("/{}/contacts", account_id);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Contact>>> {
+        let path = format!("/{}/contacts", account_id.into());
 
         self.client.get::<ContactsEndpoint>(&path, options)
     }
@@ -119,17 +172,12 @@ impl Contacts<'_> {
     /// `payload`: The `ContactPayload` with the information needed to create the contact
     pub fn create_contact(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         payload: ContactPayload,
-    ) -> Result<DNSimpleResponse<Contact>, DNSimpleError> {
-        let path = format!("/{}/contacts", account_id);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<ContactEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<Contact>> {
+        let path = format!("/{}/contacts", account_id.into());
+
+        self.client.post::<ContactEndpoint>(&path, payload)
     }
 
     /// Retrieve a contact
@@ -140,10 +188,10 @@ impl Contacts<'_> {
     /// `contact`: The contact id
     pub fn get_contact(
         &self,
-        account_id: u64,
-        contact: u64,
-    ) -> Result<DNSimpleResponse<Contact>, DNSimpleError> {
-        let path = format!("/{}/contacts/{}", account_id, contact);
+        account_id: impl Into<AccountId>,
+        contact: impl Into<ContactId>,
+    ) -> C::Result<'_, DNSimpleResponse<Contact>> {
+        let path = format!("/{}/contacts/{}", account_id.into(), contact.into());
 
         self.client.get::<ContactEndpoint>(&path, None)
     }
@@ -157,18 +205,13 @@ impl Contacts<'_> {
     /// `payload`: The `ContactPayload` with the information needed to update the contact
     pub fn update_contact(
         &self,
-        account_id: u64,
-        contact: u64,
+        account_id: impl Into<AccountId>,
+        contact: impl Into<ContactId>,
         payload: ContactPayload,
-    ) -> Result<DNSimpleResponse<Contact>, DNSimpleError> {
-        let path = format!("/{}/contacts/{}", account_id, contact);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.patch::<ContactEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<Contact>> {
+        let path = format!("/{}/contacts/{}", account_id.into(), contact.into());
+
+        self.client.patch::<ContactEndpoint>(&path, payload)
     }
 
     /// Delete a contact
@@ -179,11 +222,32 @@ impl Contacts<'_> {
     /// `contact`: The contact id
     pub fn delete_contact(
         &self,
-        account_id: u64,
-        contact: u64,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/contacts/{}", account_id, contact);
+        account_id: impl Into<AccountId>,
+        contact: impl Into<ContactId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let path = format!("/{}/contacts/{}", account_id.into(), contact.into());
 
         self.client.delete(&path)
     }
 }
+
+impl Contacts<Client> {
+    /// Lists all the contacts, fetching the pages lazily (see `list_contacts`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_contacts_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Contact> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+
+        Paginator::new(options, move |options| {
+            service.list_contacts(account_id, Some(options))
+        })
+    }
+}
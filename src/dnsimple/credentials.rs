@@ -0,0 +1,151 @@
+use crate::errors::DNSimpleError;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::fmt;
+
+/// How the requests are authenticated
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{Client, Credentials};
+///
+/// let client = Client::builder(String::new())
+///     .credentials(Credentials::basic("user@example.com", "PASSWORD"))
+///     .build()
+///     .unwrap();
+///
+/// let accounts = client.accounts().list_accounts().unwrap().data.unwrap();
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credentials {
+    /// An API access token (of an account or a user) or an OAuth access token.
+    Bearer(String),
+    /// The email and password of a DNSimple user, accepted for the user-level calls.
+    Basic {
+        /// The email of the user.
+        email: String,
+        /// The password of the user.
+        password: String,
+    },
+}
+
+impl Credentials {
+    /// Authenticates with the given access token.
+    pub fn bearer(token: impl Into<String>) -> Credentials {
+        Credentials::Bearer(token.into())
+    }
+
+    /// Authenticates with HTTP Basic, using the email and password of a user.
+    pub fn basic(email: impl Into<String>, password: impl Into<String>) -> Credentials {
+        Credentials::Basic {
+            email: email.into(),
+            password: password.into(),
+        }
+    }
+
+    /// The value of the `Authorization` header of the requests.
+    pub fn authorization(&self) -> String {
+        match self {
+            Credentials::Bearer(token) => format!("Bearer {}", token),
+            Credentials::Basic { email, password } => {
+                format!(
+                    "Basic {}",
+                    STANDARD.encode(format!("{}:{}", email, password))
+                )
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credentials::Bearer(_) => f.write_str("Bearer([FILTERED])"),
+            Credentials::Basic { email, .. } => f
+                .debug_struct("Basic")
+                .field("email", email)
+                .field("password", &"[FILTERED]")
+                .finish(),
+        }
+    }
+}
+
+/// Gives the credentials of every request sent by a client
+///
+/// The provider is asked before each attempt of a request, so the credentials can be rotated
+/// at runtime (i.e. read from a secret store) without rebuilding the client. It is called
+/// on the task sending the request, so keep it quick and cache what is slow to get.
+/// `Credentials` and any `Fn() -> Result<Credentials, DNSimpleError>` are providers.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::sync::{Arc, RwLock};
+/// use dnsimple::dnsimple::{Client, Credentials};
+///
+/// let token = Arc::new(RwLock::new(String::from("AUTH_TOKEN")));
+/// let current = token.clone();
+///
+/// let client = Client::builder(String::new())
+///     .credentials(move || Ok(Credentials::bearer(current.read().unwrap().clone())))
+///     .build()
+///     .unwrap();
+///
+/// *token.write().unwrap() = String::from("ROTATED_TOKEN");
+/// client.identity().whoami().unwrap();
+/// ```
+pub trait CredentialsProvider: Send + Sync {
+    /// The credentials to send with the next request.
+    ///
+    /// An error fails the request without sending it.
+    fn credentials(&self) -> Result<Credentials, DNSimpleError>;
+}
+
+impl CredentialsProvider for Credentials {
+    fn credentials(&self) -> Result<Credentials, DNSimpleError> {
+        Ok(self.clone())
+    }
+}
+
+impl<F> CredentialsProvider for F
+where
+    F: Fn() -> Result<Credentials, DNSimpleError> + Send + Sync,
+{
+    fn credentials(&self) -> Result<Credentials, DNSimpleError> {
+        self()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::credentials::Credentials;
+
+    #[test]
+    fn authorizes_with_a_bearer_token() {
+        assert_eq!(
+            "Bearer some-token",
+            Credentials::bearer("some-token").authorization()
+        );
+    }
+
+    #[test]
+    fn authorizes_with_http_basic() {
+        assert_eq!(
+            "Basic dXNlckBleGFtcGxlLmNvbTpzZWNyZXQ=",
+            Credentials::basic("user@example.com", "secret").authorization()
+        );
+    }
+
+    #[test]
+    fn does_not_show_the_secrets() {
+        let debug = format!(
+            "{:?} {:?}",
+            Credentials::bearer("some-token"),
+            Credentials::basic("user@example.com", "secret")
+        );
+
+        assert!(!debug.contains("some-token"));
+        assert!(!debug.contains("secret"));
+    }
+}
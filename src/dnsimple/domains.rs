@@ -1,5 +1,10 @@
-use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, DomainRef};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{optional_date, optional_timestamp, timestamp, Date, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, Paginate,
+    RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a domain
@@ -22,13 +27,17 @@ pub struct Domain {
     /// Set to true if the domain is WHOIS protected
     pub private_whois: bool,
     /// The day the domain will expire
-    pub expires_on: Option<String>,
+    #[serde(default, with = "optional_date")]
+    pub expires_on: Option<Date>,
     /// The exact expiration time of the domain
-    pub expires_at: Option<String>,
+    #[serde(default, with = "optional_timestamp")]
+    pub expires_at: Option<Timestamp>,
     /// When the domain was created
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the domain was last updated
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Represents the payload to be send when creating a domain
@@ -37,6 +46,78 @@ pub struct DomainCreationPayload {
     pub name: String,
 }
 
+/// The sorting policies of `list_domains`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DomainSort {
+    IdAsc,
+    IdDesc,
+    NameAsc,
+    NameDesc,
+    ExpirationAsc,
+    ExpirationDesc,
+}
+
+impl DomainSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DomainSort::IdAsc => "id:asc",
+            DomainSort::IdDesc => "id:desc",
+            DomainSort::NameAsc => "name:asc",
+            DomainSort::NameDesc => "name:desc",
+            DomainSort::ExpirationAsc => "expiration:asc",
+            DomainSort::ExpirationDesc => "expiration:desc",
+        }
+    }
+}
+
+/// The filters, sorting and pagination of the domains list
+///
+/// Converts into the `RequestOptions` taken by `list_domains` and `list_domains_iter`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+/// use dnsimple::dnsimple::domains::{DomainListOptions, DomainSort};
+///
+/// let client = new_client(true, String::from("AUTH_TOKEN"));
+/// let options = DomainListOptions {
+///     registrant_id: Some(42),
+///     sort: Some(DomainSort::ExpirationAsc),
+///     ..Default::default()
+/// };
+///
+/// let domains = client.domains().list_domains_iter(1234, Some(options.into())).collect_all();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DomainListOptions {
+    /// Only the domains whose name contains this string.
+    pub name_like: Option<String>,
+    /// Only the domains registered with this contact.
+    pub registrant_id: Option<u64>,
+    /// The sorting policy.
+    pub sort: Option<DomainSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<DomainListOptions> for RequestOptions {
+    fn from(options: DomainListOptions) -> Self {
+        RequestOptions::typed(
+            vec![
+                ("name_like", options.name_like),
+                (
+                    "registrant_id",
+                    options.registrant_id.map(|id| id.to_string()),
+                ),
+            ],
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct DomainsEndpoint;
 
 impl Endpoint for DomainsEndpoint {
@@ -52,11 +133,12 @@ impl Endpoint for DomainEndpoint {
 /// The Domains Service handles the domains endpoint of the DNSimple API.
 ///
 /// See [API Documentation: domains](https://developer.dnsimple.com/v2/domains/)
-pub struct Domains<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Domains<C = Client> {
+    pub client: C,
 }
 
-impl Domains<'_> {
+impl<C: ApiClient> Domains<C> {
     /// Lists the domains in the account
     ///
     /// # Examples
@@ -72,15 +154,15 @@ impl Domains<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `options`: The `RequestOptions`
+    /// `options`: The `RequestOptions` (i.e. a `DomainListOptions` converted with `into()`)
     ///             - Filters: `name_like`, `registrant_id`
     ///             - Sorting: `id`, `name`, `expiration`
     pub fn list_domains(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Domain>>, DNSimpleError> {
-        let path = format!("/{}/domains", account_id);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Domain>>> {
+        let path = format!("/{}/domains", account_id.into());
         self.client.get::<DomainsEndpoint>(&path, options)
     }
 
@@ -101,23 +183,19 @@ impl Domains<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `name`: The name of the domain we want to create
+    /// `name`: The name of the domain we want to create (normalized, see `normalize_domain`)
     // pub fn create_domain(&self, account_id: u64, name: String) -> DNSimpleResponse<DomainData> {
     pub fn create_domain(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         name: String,
-    ) -> Result<DNSimpleResponse<Domain>, DNSimpleError> {
-        let path = format!("/{}/domains", account_id);
+    ) -> C::Result<'_, DNSimpleResponse<Domain>> {
+        let name = normalize!(self.client, name).to_string();
+        let path = format!("/{}/domains", account_id.into());
 
         let payload = DomainCreationPayload { name };
 
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<DomainEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+        self.client.post::<DomainEndpoint>(&path, payload)
     }
 
     /// Retrieves the details of an existing domain.
@@ -135,14 +213,16 @@ impl Domains<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `domain_id`: The ID of the domain we want to retrieve
+    /// `domain`: The ID or name of the domain we want to retrieve
     // pub fn get_domain(&self, account_id: u64, domain_id: u64) -> DNSimpleResponse<DomainData> {
     pub fn get_domain(
         &self,
-        account_id: u64,
-        domain_id: u64,
-    ) -> Result<DNSimpleResponse<Domain>, DNSimpleError> {
-        let path = format!("/{}/domains/{}", account_id, domain_id);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Domain>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}", account_id.into(), domain);
         self.client.get::<DomainEndpoint>(&path, None)
     }
 
@@ -161,14 +241,37 @@ impl Domains<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `domain_id`: The ID of the domain we want to permanently delete
+    /// `domain`: The ID or name of the domain we want to permanently delete
     pub fn delete_domain(
         &self,
-        account_id: u64,
-        domain_id: u64,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/domains/{}", account_id, domain_id);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}", account_id.into(), domain);
 
         self.client.delete(&path)
     }
 }
+
+impl Domains<Client> {
+    /// Lists all the domains, fetching the pages lazily (see `list_domains`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_domains_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Domain> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+
+        Paginator::new(options, move |options| {
+            service.list_domains(account_id, Some(options))
+        })
+    }
+}
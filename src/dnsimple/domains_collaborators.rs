@@ -1,6 +1,10 @@
 use crate::dnsimple::domains::Domains;
-use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, CollaboratorId, DomainRef};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{optional_timestamp, timestamp, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a collaborator
@@ -19,11 +23,14 @@ pub struct Collaborator {
     /// Invitation
     pub invitation: bool,
     ///  When the collaborator was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the collaborator was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
     /// When the collaborator has accepted the invitation.
-    pub accepted_at: Option<String>,
+    #[serde(default, with = "optional_timestamp")]
+    pub accepted_at: Option<Timestamp>,
 }
 
 /// The payload used to add a collaborator
@@ -48,7 +55,7 @@ impl Endpoint for CollaboratorEndpoint {
 /// The domains collaborators set of endpoints
 ///
 /// See [API Documentation: domains/collaborators](https://developer.dnsimple.com/v2/domains/collaborators)
-impl Domains<'_> {
+impl<C: ApiClient> Domains<C> {
     /// List collaborators for the domain in the account.
     ///
     /// # Examples
@@ -64,16 +71,18 @@ impl Domains<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `domain_id`: The ID of the domain we want to list the collaborators from
+    /// `domain`: The ID or name of the domain we want to list the collaborators from
     /// `options`: The `RequestOptions`
     ///            - Pagination
     pub fn list_collaborators(
         &self,
-        account_id: u64,
-        domain_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Collaborator>>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/collaborators", account_id, domain_id);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Collaborator>>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/collaborators", account_id.into(), domain);
 
         self.client.get::<ListCollaboratorsEndpoint>(&path, options)
     }
@@ -100,25 +109,22 @@ impl Domains<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `domain_id`: The ID of the domain we want to list the collaborators of
+    /// `domain`: The ID or name of the domain we want to list the collaborators of
     /// `email`: The email of the collaborator to be added
     pub fn add_collaborator(
         &self,
-        account_id: u64,
-        domain_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         email: &str,
-    ) -> Result<DNSimpleResponse<Collaborator>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/collaborators", account_id, domain_id);
+    ) -> C::Result<'_, DNSimpleResponse<Collaborator>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/collaborators", account_id.into(), domain);
 
         let payload = AddCollaboratorPayload {
             email: email.into(),
         };
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<CollaboratorEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+        self.client.post::<CollaboratorEndpoint>(&path, payload)
     }
 
     /// Removes a collaborator from a domain
@@ -136,18 +142,46 @@ impl Domains<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `domain_id`: The ID of the domain we want to permanently delete
+    /// `domain`: The ID or name of the domain we want to permanently delete
     /// `collaborator_id`: The id of the collaborator we want to remove from the domain
     pub fn remove_collaborator(
         &self,
-        account_id: u64,
-        domain_id: u64,
-        collaborator_id: u64,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        collaborator_id: impl Into<CollaboratorId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/collaborators/{}",
-            account_id, domain_id, collaborator_id
+            account_id.into(),
+            domain,
+            collaborator_id.into()
         );
         self.client.delete(&path)
     }
 }
+
+impl Domains<Client> {
+    /// Lists all the collaborators, fetching the pages lazily (see `list_collaborators`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `domain`: The ID or name of the domain we want to list the collaborators from
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_collaborators_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Collaborator> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+        let domain: DomainRef = domain.into();
+
+        Paginator::new(options, move |options| {
+            service.list_collaborators(account_id, domain.clone(), Some(options))
+        })
+    }
+}
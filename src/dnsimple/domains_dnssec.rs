@@ -1,6 +1,7 @@
 use crate::dnsimple::domains::Domains;
-use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, DomainRef};
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
+use crate::dnsimple::{normalize, ApiClient, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -10,9 +11,11 @@ pub struct Dnssec {
     /// True if DNSSEC is enabled on the domain, otherwise false
     pub enabled: bool,
     /// When DNSSEC was enabled (or disabled)
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When DNSSEC was last updated
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 struct DnssecStatusEndpoint;
@@ -24,7 +27,7 @@ impl Endpoint for DnssecStatusEndpoint {
 /// The domains dnssec set of endpoints
 ///
 /// See [API Documentation: domains/dnssec](https://developer.dnsimple.com/v2/domains/dnssec)
-impl Domains<'_> {
+impl<C: ApiClient> Domains<C> {
     /// Enable DNSSEC for the domain in the account. This will sign the zone. If the domain is
     /// registered it will also add the DS record to the corresponding registry.
     ///
@@ -43,10 +46,12 @@ impl Domains<'_> {
     /// `domain`: The ID or name of the domain we want to enable DNSSEC on
     pub fn enable_dnssec(
         &self,
-        account_id: u64,
-        domain: &str,
-    ) -> Result<DNSimpleResponse<Dnssec>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/dnssec", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Dnssec>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/dnssec", account_id.into(), domain);
 
         self.client.post::<DnssecStatusEndpoint>(&path, Value::Null)
     }
@@ -68,10 +73,12 @@ impl Domains<'_> {
     /// `domain`: The ID or name of the domain we want to disable DNSSEC on
     pub fn disable_dnssec(
         &self,
-        account_id: u64,
-        domain: &str,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/domains/{}/dnssec", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/dnssec", account_id.into(), domain);
 
         self.client.delete(&path)
     }
@@ -93,10 +100,12 @@ impl Domains<'_> {
     /// `domain`: The ID or name of the domain we want retrieve the DNSSEC status from
     pub fn get_dnssec(
         &self,
-        account_id: u64,
-        domain: &str,
-    ) -> Result<DNSimpleResponse<Dnssec>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/dnssec", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Dnssec>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/dnssec", account_id.into(), domain);
 
         self.client.get::<DnssecStatusEndpoint>(&path, None)
     }
@@ -1,8 +1,59 @@
 use crate::dnsimple::domains::Domains;
-use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, DomainRef, EmailForwardId};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, Paginate,
+    RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
+/// The sorting policies of `list_email_forwards`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailForwardSort {
+    IdAsc,
+    IdDesc,
+    FromAsc,
+    FromDesc,
+    ToAsc,
+    ToDesc,
+}
+
+impl EmailForwardSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailForwardSort::IdAsc => "id:asc",
+            EmailForwardSort::IdDesc => "id:desc",
+            EmailForwardSort::FromAsc => "from:asc",
+            EmailForwardSort::FromDesc => "from:desc",
+            EmailForwardSort::ToAsc => "to:asc",
+            EmailForwardSort::ToDesc => "to:desc",
+        }
+    }
+}
+
+/// The sorting and pagination of the email forwards list
+///
+/// Converts into the `RequestOptions` taken by `list_email_forwards` and `list_email_forwards_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct EmailForwardListOptions {
+    /// The sorting policy.
+    pub sort: Option<EmailForwardSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<EmailForwardListOptions> for RequestOptions {
+    fn from(options: EmailForwardListOptions) -> Self {
+        RequestOptions::typed(
+            Vec::new(),
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct EmailForwardsListEndpoint;
 
 impl Endpoint for EmailForwardsListEndpoint {
@@ -25,9 +76,11 @@ pub struct EmailForward {
     /// The full email address to forward to.
     pub to: String,
     ///  When the email forward was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// Then the email forward was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Represents a shortened email forwards
@@ -42,9 +95,11 @@ pub struct EmailForwardsInList {
     /// The full email address to forward to.
     pub to: String,
     ///  When the email forward was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// Then the email forward was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// The payload used to create an email forward
@@ -65,7 +120,7 @@ impl Endpoint for EmailForwardEndpoint {
 /// The domains email forwards set of endpoints
 ///
 /// See [API Documentation: domains/email-forwards](https://developer.dnsimple.com/v2/domains/email-forwards)
-impl Domains<'_> {
+impl<C: ApiClient> Domains<C> {
     /// List email forwards for the domain in the account.
     ///
     /// # Examples
@@ -81,16 +136,18 @@ impl Domains<'_> {
     ///
     /// `account_id`: The account ID
     /// `domain`: The ID or name of the domain we want list the email forwards
-    /// `options`: The `RequestOptions`
+    /// `options`: The `RequestOptions` (i.e. an `EmailForwardListOptions` converted with `into()`)
     ///            - Sort: `id`, `from`, `to`
     ///            - Pagination
     pub fn list_email_forwards(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<EmailForwardsInList>>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/email_forwards", account_id, domain);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<EmailForwardsInList>>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/email_forwards", account_id.into(), domain);
 
         self.client.get::<EmailForwardsListEndpoint>(&path, options)
     }
@@ -118,18 +175,15 @@ impl Domains<'_> {
     /// `payload`: The `EmailForwardPayload` with the data needed to create the email forward
     pub fn create_email_forward(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: EmailForwardPayload,
-    ) -> Result<DNSimpleResponse<EmailForward>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/email_forwards", account_id, domain);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<EmailForwardEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<EmailForward>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/email_forwards", account_id.into(), domain);
+
+        self.client.post::<EmailForwardEndpoint>(&path, payload)
     }
 
     /// Retrieve an email forward
@@ -150,13 +204,17 @@ impl Domains<'_> {
     /// `email_forward`: The email forward id
     pub fn get_email_forward(
         &self,
-        account_id: u64,
-        domain: &str,
-        email_forward: u64,
-    ) -> Result<DNSimpleResponse<EmailForward>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        email_forward: impl Into<EmailForwardId>,
+    ) -> C::Result<'_, DNSimpleResponse<EmailForward>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/email_forwards/{}",
-            account_id, domain, email_forward
+            account_id.into(),
+            domain,
+            email_forward.into()
         );
 
         self.client.get::<EmailForwardEndpoint>(&path, None)
@@ -180,15 +238,43 @@ impl Domains<'_> {
     /// `email_forward`: The email forward id
     pub fn delete_email_forward(
         &self,
-        account_id: u64,
-        domain: &str,
-        email_forward: i32,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        email_forward: impl Into<EmailForwardId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/email_forwards/{}",
-            account_id, domain, email_forward
+            account_id.into(),
+            domain,
+            email_forward.into()
         );
 
         self.client.delete(&path)
     }
 }
+
+impl Domains<Client> {
+    /// Lists all the email forwards, fetching the pages lazily (see `list_email_forwards`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `domain`: The ID or name of the domain we want list the email forwards
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_email_forwards_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<EmailForwardsInList> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+        let domain: DomainRef = domain.into();
+
+        Paginator::new(options, move |options| {
+            service.list_email_forwards(account_id, domain.clone(), Some(options))
+        })
+    }
+}
@@ -1,6 +1,10 @@
 use crate::dnsimple::domains::Domains;
-use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, DomainRef, PushId};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{optional_timestamp, timestamp, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 struct DomainPushesListEndpoint;
@@ -27,11 +31,14 @@ pub struct DomainPush {
     /// The associated account ID.
     pub account_id: u64,
     /// When the domain push was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the domain push was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
     /// When the domain push was accepted in DNSimple.
-    pub accepted_at: Option<String>,
+    #[serde(default, with = "optional_timestamp")]
+    pub accepted_at: Option<Timestamp>,
 }
 
 /// Payload to initiate a push
@@ -44,7 +51,7 @@ pub struct InitiatePushPayload {
 /// The domains push set of endpoints
 ///
 /// See [API Documentation: domains/pushes](https://developer.dnsimple.com/v2/domains/pushes)
-impl Domains<'_> {
+impl<C: ApiClient> Domains<C> {
     /// Initiate a push
     ///
     /// # Examples
@@ -67,18 +74,15 @@ impl Domains<'_> {
     /// `payload`: The `InitiatePushPayload` used to initiate a push
     pub fn initiate_push(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: InitiatePushPayload,
-    ) -> Result<DNSimpleResponse<DomainPush>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/pushes", account_id, domain);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<DomainPushEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<DomainPush>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/pushes", account_id.into(), domain);
+
+        self.client.post::<DomainPushEndpoint>(&path, payload)
     }
 
     /// List pending pushes for the target account.
@@ -99,10 +103,10 @@ impl Domains<'_> {
     ///            - Pagination
     pub fn list_pushes(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<DomainPush>>, DNSimpleError> {
-        let path = format!("/{}/domains/pushes", account_id);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<DomainPush>>> {
+        let path = format!("/{}/domains/pushes", account_id.into());
 
         self.client.get::<DomainPushesListEndpoint>(&path, options)
     }
@@ -124,10 +128,10 @@ impl Domains<'_> {
     /// `push_id`: The push id
     pub fn accept_push(
         &self,
-        account_id: u64,
-        push_id: u64,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/domains/pushes/{}", account_id, push_id);
+        account_id: impl Into<AccountId>,
+        push_id: impl Into<PushId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let path = format!("/{}/domains/pushes/{}", account_id.into(), push_id.into());
 
         self.client.empty_post(&path)
     }
@@ -149,11 +153,32 @@ impl Domains<'_> {
     /// `push_id`: The push id
     pub fn reject_push(
         &self,
-        account_id: u64,
-        push_id: u64,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/domains/pushes/{}", account_id, push_id);
+        account_id: impl Into<AccountId>,
+        push_id: impl Into<PushId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let path = format!("/{}/domains/pushes/{}", account_id.into(), push_id.into());
 
         self.client.delete(&path)
     }
 }
+
+impl Domains<Client> {
+    /// Lists all the pushes, fetching the pages lazily (see `list_pushes`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_pushes_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<DomainPush> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+
+        Paginator::new(options, move |options| {
+            service.list_pushes(account_id, Some(options))
+        })
+    }
+}
@@ -1,6 +1,11 @@
 use crate::dnsimple::domains::Domains;
-use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, DelegationSignerRecordId, DomainRef};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, Paginate,
+    RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a delegation signer record
@@ -21,9 +26,53 @@ pub struct DelegationSignerRecord {
     /// The public key that references the corresponding DNSKEY record.
     pub public_key: Option<String>,
     /// When the delegation signing record was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the delegation signing record was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
+}
+
+/// The sorting policies of `list_delegation_signer_records`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelegationSignerRecordSort {
+    IdAsc,
+    IdDesc,
+    CreatedAtAsc,
+    CreatedAtDesc,
+}
+
+impl DelegationSignerRecordSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DelegationSignerRecordSort::IdAsc => "id:asc",
+            DelegationSignerRecordSort::IdDesc => "id:desc",
+            DelegationSignerRecordSort::CreatedAtAsc => "created_at:asc",
+            DelegationSignerRecordSort::CreatedAtDesc => "created_at:desc",
+        }
+    }
+}
+
+/// The sorting and pagination of the delegation signer records list
+///
+/// Converts into the `RequestOptions` taken by `list_delegation_signer_records` and `list_delegation_signer_records_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct DelegationSignerRecordListOptions {
+    /// The sorting policy.
+    pub sort: Option<DelegationSignerRecordSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<DelegationSignerRecordListOptions> for RequestOptions {
+    fn from(options: DelegationSignerRecordListOptions) -> Self {
+        RequestOptions::typed(
+            Vec::new(),
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
 }
 
 struct ListSignerRecordsEndpoint;
@@ -56,7 +105,7 @@ impl Endpoint for SignerRecordEndpoint {
 /// The domains signer records set of endpoints
 ///
 /// See [API Documentation: domains/dnssec](https://developer.dnsimple.com/v2/domains/dnssec)
-impl Domains<'_> {
+impl<C: ApiClient> Domains<C> {
     /// List delegation signer records for the domain in the account.
     ///
     /// # Examples
@@ -72,16 +121,18 @@ impl Domains<'_> {
     ///
     /// `account_id`: The account ID
     /// `domain`: The ID or name of the domain we want list the signer records from
-    /// `options` The `RequestOptions`
+    /// `options` The `RequestOptions` (i.e. a `DelegationSignerRecordListOptions` converted with `into()`)
     ///           - Sort: `id`, `created_at`
     ///           - Pagination
     pub fn list_delegation_signer_records(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<DelegationSignerRecord>>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/ds_records", account_id, domain);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<DelegationSignerRecord>>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/ds_records", account_id.into(), domain);
 
         self.client.get::<ListSignerRecordsEndpoint>(&path, options)
     }
@@ -116,18 +167,15 @@ impl Domains<'_> {
     /// `payload`: The `SignerRecordPayload` with the data needed to create the delegation signer record
     pub fn create_delegation_signer_record(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: DelegationSignerRecordPayload,
-    ) -> Result<DNSimpleResponse<DelegationSignerRecord>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/ds_records", account_id, domain);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<SignerRecordEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<DelegationSignerRecord>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/ds_records", account_id.into(), domain);
+
+        self.client.post::<SignerRecordEndpoint>(&path, payload)
     }
 
     /// Get the delegation signer record under the domain for the account
@@ -147,10 +195,12 @@ impl Domains<'_> {
     /// `domain`: The ID or name of the domain we want list the signer records from
     pub fn get_delegation_signer_record(
         &self,
-        account_id: u64,
-        domain: &str,
-    ) -> Result<DNSimpleResponse<DelegationSignerRecord>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/ds_records", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<DelegationSignerRecord>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/ds_records", account_id.into(), domain);
 
         self.client.get::<SignerRecordEndpoint>(&path, None)
     }
@@ -173,15 +223,43 @@ impl Domains<'_> {
     /// `ds_record_id`: The delegation signer record id
     pub fn delete_delegation_signer_record(
         &self,
-        account_id: u64,
-        domain: &str,
-        delegation_signer_record_id: i32,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        delegation_signer_record_id: impl Into<DelegationSignerRecordId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/domains/{}/ds_records/{}",
-            account_id, domain, delegation_signer_record_id
+            account_id.into(),
+            domain,
+            delegation_signer_record_id.into()
         );
 
         self.client.delete(&path)
     }
 }
+
+impl Domains<Client> {
+    /// Lists all the delegation signer records, fetching the pages lazily (see `list_delegation_signer_records`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `domain`: The ID or name of the domain we want list the signer records from
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_delegation_signer_records_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<DelegationSignerRecord> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+        let domain: DomainRef = domain.into();
+
+        Paginator::new(options, move |options| {
+            service.list_delegation_signer_records(account_id, domain.clone(), Some(options))
+        })
+    }
+}
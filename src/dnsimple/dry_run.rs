@@ -0,0 +1,149 @@
+use crate::dnsimple::HttpResponse;
+use serde_json::Value;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// A request the `Client` would have sent if it was not in dry-run mode
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedOperation {
+    /// The HTTP method (`POST`, `PUT`, `PATCH` or `DELETE`).
+    pub method: String,
+    /// The path of the request, relative to the versioned url (i.e. `/1010/zones/example.com/records`).
+    pub path: String,
+    /// The query parameters.
+    pub query: Vec<(String, String)>,
+    /// The JSON payload, if any.
+    pub payload: Option<Value>,
+}
+
+impl fmt::Display for PlannedOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)?;
+
+        for (index, (name, value)) in self.query.iter().enumerate() {
+            let separator = if index == 0 { '?' } else { '&' };
+            write!(f, "{}{}={}", separator, name, value)?;
+        }
+
+        match self.payload {
+            Some(ref payload) => write!(f, " {}", payload),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Records the mutating calls of a `Client` instead of sending them
+///
+/// Once a client is in dry-run mode, its `POST`, `PUT`, `PATCH` and `DELETE` requests are
+/// recorded as `PlannedOperation`s and answered with a `202 Accepted` response without any data,
+/// while its `GET` requests are still sent to the API. The OAuth token exchange is not affected.
+///
+/// The operations are shared between the clones of a `DryRun`, keep one to inspect them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{Client, DryRun};
+///
+/// let plan = DryRun::new();
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .dry_run(plan.clone())
+///     .build()
+///     .unwrap();
+///
+/// client.zones().delete_zone_record(1010, "example.com", 5).unwrap();
+///
+/// for operation in plan.operations() {
+///     println!("{}", operation);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DryRun {
+    operations: Arc<Mutex<Vec<PlannedOperation>>>,
+}
+
+impl DryRun {
+    /// Creates a dry-run without any planned operation.
+    pub fn new() -> DryRun {
+        DryRun::default()
+    }
+
+    /// The operations planned so far, in the order they were made.
+    pub fn operations(&self) -> Vec<PlannedOperation> {
+        self.lock().clone()
+    }
+
+    /// Forgets the operations planned so far.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Records the operation, returning the response the client answers with.
+    pub(crate) fn plan(&self, operation: PlannedOperation) -> HttpResponse {
+        self.lock().push(operation);
+
+        HttpResponse {
+            status: 202,
+            status_text: String::from("Accepted"),
+            headers: Vec::new(),
+            body: String::from("{}"),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<PlannedOperation>> {
+        self.operations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::dry_run::{DryRun, PlannedOperation};
+    use serde_json::json;
+
+    #[test]
+    fn displays_the_planned_operations() {
+        let with_payload = PlannedOperation {
+            method: String::from("POST"),
+            path: String::from("/1010/zones/example.com/records"),
+            query: Vec::new(),
+            payload: Some(json!({"name": "www"})),
+        };
+        let with_query = PlannedOperation {
+            method: String::from("DELETE"),
+            path: String::from("/1010/zones/example.com/records/5"),
+            query: vec![
+                (String::from("notify"), String::from("true")),
+                (String::from("force"), String::from("1")),
+            ],
+            payload: None,
+        };
+
+        assert_eq!(
+            "POST /1010/zones/example.com/records {\"name\":\"www\"}",
+            with_payload.to_string()
+        );
+        assert_eq!(
+            "DELETE /1010/zones/example.com/records/5?notify=true&force=1",
+            with_query.to_string()
+        );
+    }
+
+    #[test]
+    fn shares_the_operations_between_clones() {
+        let dry_run = DryRun::new();
+        let response = dry_run.clone().plan(PlannedOperation {
+            method: String::from("DELETE"),
+            path: String::from("/1010/webhooks/1"),
+            query: Vec::new(),
+            payload: None,
+        });
+
+        assert_eq!(202, response.status);
+        assert_eq!(1, dry_run.operations().len());
+
+        dry_run.clear();
+        assert!(dry_run.operations().is_empty());
+    }
+}
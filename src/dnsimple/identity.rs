@@ -1,7 +1,7 @@
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
 use serde::{Deserialize, Serialize};
 
-use crate::dnsimple::{Client, DNSimpleResponse, Endpoint};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::{ApiClient, Client, DNSimpleResponse, Endpoint};
 
 /// Represents a User
 #[derive(Debug, Deserialize, Serialize)]
@@ -11,9 +11,11 @@ pub struct User {
     /// The users email
     pub email: String,
     /// When the user was created in DNSimple
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the user was last updated in DNSimple
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Represents an Account
@@ -26,9 +28,11 @@ pub struct Account {
     /// The identifier of the plan the account is subscribed to
     pub plan_identifier: String,
     /// When the account was created in DNSimple
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the account was last updated in DNSimple
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Represents the structure holding a User and Account structs.
@@ -49,11 +53,12 @@ impl Endpoint for IdentityEndpoint {
 /// The Identity Service handles the identity (whoami) endpoint of the DNSimple API.
 ///
 /// See [API Documentation: identity](https://developer.dnsimple.com/v2/identity/)
-pub struct Identity<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Identity<C = Client> {
+    pub client: C,
 }
 
-impl Identity<'_> {
+impl<C: ApiClient> Identity<C> {
     /// Retrieves the details about the current authenticated entity used to access the API.
     ///
     /// # Examples
@@ -66,7 +71,7 @@ impl Identity<'_> {
     /// let account = response.account.unwrap();
     ///
     /// ```
-    pub fn whoami(&self) -> Result<DNSimpleResponse<WhoamiData>, DNSimpleError> {
+    pub fn whoami(&self) -> C::Result<'_, DNSimpleResponse<WhoamiData>> {
         self.client.get::<IdentityEndpoint>("/whoami", None)
     }
 }
@@ -74,29 +79,34 @@ impl Identity<'_> {
 #[cfg(test)]
 mod tests {
     use crate::dnsimple::identity;
+    use crate::dnsimple::timestamps::parse_timestamp;
 
     #[test]
-    fn user_fields() {
+    fn user_fields() -> Result<(), String> {
         let user = identity::User {
             id: 12,
             email: String::from("testing@dnsimple.com"),
-            created_at: String::from("some_time_ago"),
-            updated_at: String::from("recently"),
+            created_at: parse_timestamp("2014-01-15T21:59:04Z")?,
+            updated_at: parse_timestamp("2015-06-25T12:10:44Z")?,
         };
 
-        assert_eq!("testing@dnsimple.com", user.email)
+        assert_eq!("testing@dnsimple.com", user.email);
+
+        Ok(())
     }
 
     #[test]
-    fn account_fields() {
+    fn account_fields() -> Result<(), String> {
         let account = identity::Account {
             id: 14,
             email: String::from("account@dnsimple.com"),
             plan_identifier: String::from("testing_plan"),
-            created_at: String::from("some_time_ago"),
-            updated_at: String::from("recently"),
+            created_at: parse_timestamp("2014-01-15T21:59:04Z")?,
+            updated_at: parse_timestamp("2015-06-25T12:10:44Z")?,
         };
 
-        assert_eq!("testing_plan", account.plan_identifier)
+        assert_eq!("testing_plan", account.plan_identifier);
+
+        Ok(())
     }
 }
@@ -0,0 +1,277 @@
+//! The identifiers accepted by the services
+//!
+//! Every method takes its identifiers as `impl Into<...>`, so plain values keep working
+//! (`1010`, `"example.com"`, `String::from("example.com")`, the `id` of a model...) while the
+//! signatures say what each argument is.
+
+use crate::errors::DNSimpleError;
+use idna::AsciiDenyList;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt;
+
+macro_rules! id {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> $name {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> u64 {
+                id.0
+            }
+        }
+
+        impl PartialEq<u64> for $name {
+            fn eq(&self, other: &u64) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$name> for u64 {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+macro_rules! reference {
+    ($(#[$doc:meta])* $name:ident, $(#[$by_name_doc:meta])* $by_name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            /// By id.
+            Id(u64),
+            $(#[$by_name_doc])*
+            $by_name(String),
+        }
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> $name {
+                $name::Id(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> $name {
+                $name::$by_name(value.to_string())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> $name {
+                $name::$by_name(value)
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(value: &String) -> $name {
+                $name::$by_name(value.clone())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(value: &$name) -> $name {
+                value.clone()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $name::Id(id) => id.fmt(f),
+                    $name::$by_name(value) => f.write_str(value),
+                }
+            }
+        }
+    };
+}
+
+id!(
+    /// The id of an account
+    AccountId
+);
+id!(
+    /// The id of a record of a zone
+    ZoneRecordId
+);
+id!(
+    /// The id of a contact
+    ContactId
+);
+id!(
+    /// The id of a webhook
+    WebhookId
+);
+id!(
+    /// The id of a collaborator of a domain
+    CollaboratorId
+);
+id!(
+    /// The id of an email forward
+    EmailForwardId
+);
+id!(
+    /// The id of a domain push
+    PushId
+);
+id!(
+    /// The id of a delegation signer record
+    DelegationSignerRecordId
+);
+id!(
+    /// The id of a certificate
+    CertificateId
+);
+id!(
+    /// The id of a certificate renewal
+    CertificateRenewalId
+);
+id!(
+    /// The id of a domain registration
+    DomainRegistrationId
+);
+id!(
+    /// The id of a domain renewal
+    DomainRenewalId
+);
+id!(
+    /// The id of a domain transfer
+    DomainTransferId
+);
+id!(
+    /// The id of a record of a template
+    TemplateRecordId
+);
+
+reference!(
+    /// A domain (or its zone), by id or by name
+    ///
+    /// `"example.com".into()` and `181984.into()` both make one.
+    DomainRef,
+    /// By name (i.e. `example.com`).
+    Name
+);
+impl DomainRef {
+    /// The domain with its name normalized as by `normalize_domain` (ids are kept as they are)
+    ///
+    /// The services normalize the domains and zones they are given before sending anything.
+    pub fn normalize(self) -> Result<DomainRef, DNSimpleError> {
+        match self {
+            DomainRef::Id(id) => Ok(DomainRef::Id(id)),
+            DomainRef::Name(name) => normalize_domain(&name).map(DomainRef::Name),
+        }
+    }
+}
+
+/// Normalizes a domain (or zone) name the way the API expects it
+///
+/// The trailing dot is stripped, then the name is lowercased and its Unicode labels are
+/// converted to A-labels (punycode) as described by IDNA/UTS-46, i.e. `Bücher.Example.` gives
+/// `xn--bcher-kva.example`.
+///
+/// Fails with `DNSimpleError::InvalidDomain` when the name is empty, is not a valid IDNA name
+/// or has characters that cannot be part of a domain in an url (spaces, `/`, `?`, `#`...).
+pub fn normalize_domain(name: &str) -> Result<String, DNSimpleError> {
+    let invalid = |reason: &str| DNSimpleError::InvalidDomain {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    };
+    let stripped = name.strip_suffix('.').unwrap_or(name);
+
+    if stripped.is_empty() {
+        return Err(invalid("the name is empty"));
+    }
+
+    idna::domain_to_ascii_cow(stripped.as_bytes(), AsciiDenyList::URL)
+        .map(Cow::into_owned)
+        .map_err(|_| invalid("the name is not a valid IDNA domain name"))
+}
+
+reference!(
+    /// A template, by id or by short name
+    TemplateRef,
+    /// By short name (i.e. `alpha`).
+    Sid
+);
+reference!(
+    /// A one-click service, by id or by short name
+    ServiceRef,
+    /// By short name (i.e. `wordpress`).
+    Sid
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::ids::{normalize_domain, AccountId, DomainRef};
+    use crate::errors::DNSimpleError;
+
+    #[test]
+    fn converts_the_plain_values() {
+        assert_eq!(AccountId(1010), AccountId::from(1010));
+        assert_eq!(1010_u64, u64::from(AccountId(1010)));
+        assert_eq!(DomainRef::Id(42), DomainRef::from(42));
+        assert_eq!(
+            DomainRef::Name(String::from("example.com")),
+            DomainRef::from("example.com")
+        );
+    }
+
+    #[test]
+    fn formats_as_path_segments() {
+        assert_eq!("1010", AccountId(1010).to_string());
+        assert_eq!("42", DomainRef::from(42).to_string());
+        assert_eq!("example.com", DomainRef::from("example.com").to_string());
+    }
+
+    #[test]
+    fn normalizes_the_domain_names() {
+        assert_eq!(
+            Ok(String::from("example.com")),
+            normalize_domain("Example.COM.")
+        );
+        assert_eq!(
+            Ok(String::from("xn--bcher-kva.example")),
+            normalize_domain("bücher.example")
+        );
+        assert_eq!(
+            Ok(String::from("xn--bcher-kva.example")),
+            normalize_domain("xn--bcher-kva.example")
+        );
+        assert_eq!(Ok(DomainRef::Id(42)), DomainRef::Id(42).normalize());
+    }
+
+    #[test]
+    fn rejects_the_invalid_domain_names() {
+        for name in [
+            "",
+            ".",
+            "exa mple.com",
+            "example.com/records",
+            "xn--a.example",
+        ] {
+            assert!(
+                matches!(
+                    normalize_domain(name),
+                    Err(DNSimpleError::InvalidDomain { .. })
+                ),
+                "{} should be rejected",
+                name
+            );
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Traces the calls made by the `Client` when the `tracing` feature is enabled.
+//!
+//! Every call gets a `dnsimple.request` span with the HTTP method, the templated path (i.e.
+//! `/{account}/zones/{zone}/records`), the status, the remaining rate limit and the latency.
+//! Failed calls also emit an event with the error. The headers (and so the token) are never
+//! recorded.
+
+use crate::dnsimple::HttpResponse;
+#[cfg(feature = "tracing")]
+use crate::dnsimple::RATE_LIMIT_REMAINING;
+use crate::errors::DNSimpleError;
+
+/// Runs `send` within the span of the call.
+#[cfg(feature = "tracing")]
+pub(crate) fn instrument(
+    method: &str,
+    path: &str,
+    send: impl FnOnce() -> Result<HttpResponse, DNSimpleError>,
+) -> Result<HttpResponse, DNSimpleError> {
+    use std::time::Instant;
+    use tracing::field::Empty;
+
+    let span = tracing::info_span!(
+        "dnsimple.request",
+        http.method = method,
+        http.path = templated_path(path).as_str(),
+        http.status = Empty,
+        rate_limit.remaining = Empty,
+        latency_ms = Empty,
+    );
+    let started = Instant::now();
+
+    let result = span.in_scope(send);
+
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+    if let Err(ref error) = result {
+        span.in_scope(|| tracing::warn!(error = %error, "DNSimple API call failed"));
+    }
+
+    result
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn instrument(
+    _method: &str,
+    _path: &str,
+    send: impl FnOnce() -> Result<HttpResponse, DNSimpleError>,
+) -> Result<HttpResponse, DNSimpleError> {
+    send()
+}
+
+/// Records the status and remaining rate limit of a response in the span of the call.
+#[cfg(feature = "tracing")]
+pub(crate) fn record_response(response: &HttpResponse) {
+    let span = tracing::Span::current();
+    let remaining = response
+        .header(RATE_LIMIT_REMAINING)
+        .and_then(|remaining| remaining.parse::<u32>().ok());
+
+    span.record("http.status", response.status);
+    if let Some(remaining) = remaining {
+        span.record("rate_limit.remaining", remaining);
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn record_response(_response: &HttpResponse) {}
+
+/// Replaces the identifiers of a path by placeholders, so calls to the same endpoint share
+/// the same path (`/1010/zones/example.com/records/5` becomes `/{account}/zones/{zone}/records/{record}`).
+#[cfg(feature = "tracing")]
+pub(crate) fn templated_path(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or_default();
+    let mut templated = String::new();
+    let mut previous = "";
+
+    for (position, segment) in path.split('/').skip(1).enumerate() {
+        templated.push('/');
+
+        if position == 0 && segment.bytes().all(|byte| byte.is_ascii_digit()) {
+            templated.push_str("{account}");
+        } else if RESOURCES.contains(&segment) {
+            templated.push_str(segment);
+        } else {
+            templated.push_str(placeholder(previous));
+        }
+
+        previous = segment;
+    }
+
+    templated
+}
+
+/// The literal segments of the API paths.
+#[cfg(feature = "tracing")]
+const RESOURCES: &[&str] = &[
+    "access_token",
+    "accounts",
+    "activation",
+    "authorize_transfer_out",
+    "auto_renewal",
+    "certificates",
+    "check",
+    "collaborators",
+    "contacts",
+    "delegation",
+    "distribution",
+    "dnssec",
+    "domains",
+    "download",
+    "ds_records",
+    "email_forwards",
+    "extended_attributes",
+    "file",
+    "issue",
+    "letsencrypt",
+    "oauth",
+    "premium_price",
+    "prices",
+    "private_key",
+    "pushes",
+    "records",
+    "registrar",
+    "registrations",
+    "renewals",
+    "services",
+    "templates",
+    "tlds",
+    "transfers",
+    "vanity",
+    "webhooks",
+    "whoami",
+    "whois_privacy",
+    "zones",
+];
+
+#[cfg(feature = "tracing")]
+fn placeholder(collection: &str) -> &'static str {
+    match collection {
+        "certificates" | "letsencrypt" => "{certificate}",
+        "collaborators" => "{collaborator}",
+        "contacts" => "{contact}",
+        "domains" | "vanity" => "{domain}",
+        "ds_records" => "{ds_record}",
+        "email_forwards" => "{email_forward}",
+        "pushes" => "{push}",
+        "records" => "{record}",
+        "registrations" => "{registration}",
+        "renewals" => "{renewal}",
+        "services" => "{service}",
+        "templates" => "{template}",
+        "tlds" => "{tld}",
+        "transfers" => "{transfer}",
+        "webhooks" => "{webhook}",
+        "zones" => "{zone}",
+        _ => "{id}",
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::dnsimple::instrumentation::templated_path;
+
+    #[test]
+    fn templates_the_identifiers_of_the_path() {
+        assert_eq!("/whoami", templated_path("/whoami"));
+        assert_eq!("/tlds/{tld}", templated_path("/tlds/com"));
+        assert_eq!(
+            "/{account}/zones/{zone}/records/{record}",
+            templated_path("/1010/zones/example.com/records/5")
+        );
+        assert_eq!(
+            "/{account}/domains/pushes/{push}",
+            templated_path("/1010/domains/pushes/42")
+        );
+        assert_eq!(
+            "/{account}/domains/{domain}/certificates/letsencrypt/{certificate}/renewals/{renewal}/issue",
+            templated_path("/1010/domains/example.com/certificates/letsencrypt/101967/renewals/65082/issue")
+        );
+        assert_eq!(
+            "/{account}/registrar/domains/{domain}/premium_price",
+            templated_path("/1010/registrar/domains/ruby.codes/premium_price?action=registration")
+        );
+    }
+}
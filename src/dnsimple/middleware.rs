@@ -0,0 +1,70 @@
+use crate::dnsimple::{HttpRequest, HttpResponse, Transport};
+use crate::errors::DNSimpleError;
+use std::sync::Arc;
+
+/// A layer around every request sent by a `Client`
+///
+/// The middlewares are run in the order they were added to the client, each one getting the
+/// request and the rest of the chain (`Next`). A middleware can change the request before
+/// passing it on, inspect or change the response (or the `DNSimpleError`) coming back, or
+/// answer without calling the rest of the chain at all.
+///
+/// The chain runs for every attempt made by the client, so a request retried by the
+/// `RetryPolicy` goes through it more than once. Error statuses are only turned into a
+/// `DNSimpleError` once the chain returns, the middlewares see them as an `HttpResponse`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{Client, HttpRequest, HttpResponse, Middleware, Next};
+/// use dnsimple::errors::DNSimpleError;
+///
+/// struct CorrelationId(String);
+///
+/// impl Middleware for CorrelationId {
+///     fn handle(&self, mut request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, DNSimpleError> {
+///         request.headers.push((String::from("X-Correlation-Id"), self.0.clone()));
+///
+///         let result = next.run(request);
+///         if let Ok(ref response) = result {
+///             println!("{} {}", response.status, self.0);
+///         }
+///         result
+///     }
+/// }
+///
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .middleware(CorrelationId(String::from("nightly-sync")))
+///     .build()
+///     .unwrap();
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Handles the request, calling `next.run` to pass it on to the rest of the chain.
+    fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, DNSimpleError>;
+}
+
+/// The rest of the middleware chain, ending with the `Transport` of the client
+pub struct Next<'a> {
+    middlewares: &'a [Arc<dyn Middleware>],
+    transport: &'a dyn Transport,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        middlewares: &'a [Arc<dyn Middleware>],
+        transport: &'a dyn Transport,
+    ) -> Self {
+        Next {
+            middlewares,
+            transport,
+        }
+    }
+
+    /// Passes the request on to the following middleware, or sends it if there is none left.
+    pub fn run(self, request: HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => middleware.handle(request, Next::new(rest, self.transport)),
+            None => self.transport.send(&request),
+        }
+    }
+}
@@ -1,5 +1,4 @@
-use crate::dnsimple::Client;
-use crate::errors::DNSimpleError;
+use crate::dnsimple::{ApiClient, Client};
 use serde::{Deserialize, Serialize};
 
 /// Represents the payload used to exchange this information for the
@@ -44,11 +43,12 @@ pub struct AccessToken {
 /// The Oauth Service is used to request access to the API
 ///
 /// See [API Documentation: oauth](https://developer.dnsimple.com/v2/oauth/)
-pub struct OAuth<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct OAuth<C = Client> {
+    pub client: C,
 }
 
-impl OAuth<'_> {
+impl<C: ApiClient> OAuth<C> {
     /// Exchange the short-lived authorization code for an access token
     /// you can use to authenticate your API calls.
     ///
@@ -76,7 +76,7 @@ impl OAuth<'_> {
     pub fn exchange_authorization_for_token(
         &self,
         payload: OAuthTokenPayload,
-    ) -> Result<AccessToken, DNSimpleError> {
+    ) -> C::Result<'_, AccessToken> {
         let path = "/oauth/access_token";
         let params = OAuthTokenParams {
             grant_type: "authorization_code".to_string(),
@@ -87,18 +87,7 @@ impl OAuth<'_> {
             state: payload.state,
         };
 
-        let value = serde_json::to_value(params)
-            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
-
-        let response = self
-            .client
-            ._agent
-            .post(&self.client.url(path))
-            .send_json(value)
-            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
-
-        response
-            .into_json::<AccessToken>()
-            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))
+        self.client
+            .unauthenticated_post::<AccessToken>(path, params)
     }
 }
@@ -0,0 +1,99 @@
+use crate::dnsimple::{DNSimpleResponse, Paginate, RequestOptions};
+use crate::errors::DNSimpleError;
+use std::collections::VecDeque;
+
+/// The number of items requested per page when the options do not set one (the API maximum).
+const DEFAULT_PER_PAGE: u32 = 100;
+
+type FetchPage<T> =
+    Box<dyn FnMut(RequestOptions) -> Result<DNSimpleResponse<Vec<T>>, DNSimpleError> + Send>;
+
+/// Iterates over all the items of a list endpoint, fetching the pages lazily
+///
+/// Returned by the `list_*_iter` functions of the services. The next page is only requested
+/// once all the items of the current one have been consumed. The iteration stops after
+/// yielding the first error.
+///
+/// The filters and sorting of the `RequestOptions` given to the `list_*_iter` function are sent
+/// with every request, its `paginate` option sets the page size and the page to start from.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+///
+/// let client = new_client(true, String::from("AUTH_TOKEN"));
+///
+/// for record in client.zones().list_zone_records_iter(1234, "example.com", None) {
+///     println!("{}", record.unwrap().name);
+/// }
+///
+/// let domains = client.domains().list_domains_iter(1234, None).collect_all().unwrap();
+/// ```
+pub struct Paginator<T> {
+    fetch: FetchPage<T>,
+    options: RequestOptions,
+    per_page: u32,
+    next_page: Option<u32>,
+    items: VecDeque<T>,
+}
+
+impl<T> Paginator<T> {
+    pub(crate) fn new(
+        options: Option<RequestOptions>,
+        fetch: impl FnMut(RequestOptions) -> Result<DNSimpleResponse<Vec<T>>, DNSimpleError>
+            + Send
+            + 'static,
+    ) -> Paginator<T> {
+        let mut options = options.unwrap_or_default();
+        let paginate = options.paginate.take();
+
+        Paginator {
+            fetch: Box::new(fetch),
+            options,
+            per_page: paginate.as_ref().map_or(DEFAULT_PER_PAGE, |p| p.per_page),
+            next_page: Some(paginate.map_or(1, |p| p.page)),
+            items: VecDeque::new(),
+        }
+    }
+
+    /// Fetches all the remaining pages, failing with the first error encountered.
+    pub fn collect_all(self) -> Result<Vec<T>, DNSimpleError> {
+        self.collect()
+    }
+
+    fn fetch_next_page(&mut self, page: u32) -> Result<(), DNSimpleError> {
+        let mut options = self.options.clone();
+        options.paginate = Some(Paginate {
+            per_page: self.per_page,
+            page,
+        });
+
+        let response = (self.fetch)(options)?;
+
+        self.items.extend(response.data.unwrap_or_default());
+        self.next_page = response
+            .pagination
+            .filter(|pagination| pagination.current_page < pagination.total_pages)
+            .and_then(|pagination| u32::try_from(pagination.current_page + 1).ok());
+
+        Ok(())
+    }
+}
+
+impl<T> Iterator for Paginator<T> {
+    type Item = Result<T, DNSimpleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.pop_front() {
+                return Some(Ok(item));
+            }
+
+            let page = self.next_page.take()?;
+            if let Err(error) = self.fetch_next_page(page) {
+                return Some(Err(error));
+            }
+        }
+    }
+}
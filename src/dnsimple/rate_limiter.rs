@@ -0,0 +1,200 @@
+use crate::dnsimple::RateLimit;
+use crate::errors::DNSimpleError;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, SystemTime};
+
+/// What the client does when sending a request would go below the rate limit floor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Waits until the rate limit resets before sending the request.
+    Block,
+    /// Fails right away with `DNSimpleError::RateLimitWouldExceed`.
+    Fail,
+}
+
+/// Keeps the client under the DNSimple API rate limit
+///
+/// The limiter tracks the `X-RateLimit-Remaining` and `X-RateLimit-Reset` headers of every
+/// response and, once the remaining requests reach the `floor`, holds the following requests
+/// back until the rate limit resets (or fails them, depending on the `RateLimitMode`).
+///
+/// The state is shared between the clones of a limiter, so a single limiter can be given to
+/// several clients (or threads) using the same token.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{Client, RateLimitMode, RateLimiter};
+///
+/// let limiter = RateLimiter::new(100, RateLimitMode::Block);
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .rate_limiter(limiter.clone())
+///     .build()
+///     .unwrap();
+///
+/// client.identity().whoami().unwrap();
+/// println!("{:?} requests remaining", limiter.remaining());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    floor: u32,
+    mode: RateLimitMode,
+    window: Arc<Mutex<Option<RateLimit>>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter keeping at least `floor` requests of the hourly quota unused
+    ///
+    /// # Arguments
+    ///
+    /// `floor`: the number of remaining requests below which requests are held back
+    /// `mode`: whether to wait for the rate limit to reset or to fail the request
+    pub fn new(floor: u32, mode: RateLimitMode) -> RateLimiter {
+        RateLimiter {
+            floor,
+            mode,
+            window: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// The number of remaining requests, if known.
+    pub fn remaining(&self) -> Option<u32> {
+        self.window().map(|window| window.remaining)
+    }
+
+    /// The time at which the current rate limit window resets, if known.
+    pub fn reset(&self) -> Option<SystemTime> {
+        self.window().map(|window| window.reset)
+    }
+
+    /// Reserves a request of the quota
+    ///
+    /// Returns how long to wait before trying again when the floor has been reached
+    /// (in `Block` mode), `None` when the request can be sent right away.
+    pub(crate) fn acquire(&self) -> Result<Option<Duration>, DNSimpleError> {
+        let mut window = self.lock();
+        let now = SystemTime::now();
+
+        match *window {
+            Some(ref mut current) if current.reset > now => {
+                if current.remaining > self.floor {
+                    current.remaining -= 1;
+                    return Ok(None);
+                }
+
+                match self.mode {
+                    RateLimitMode::Block => Ok(current.reset.duration_since(now).ok()),
+                    RateLimitMode::Fail => Err(DNSimpleError::RateLimitWouldExceed {
+                        remaining: current.remaining,
+                        reset: current.reset,
+                    }),
+                }
+            }
+            _ => {
+                *window = None;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Records the rate limit of a response (ignored if the response did not report one).
+    pub(crate) fn update(&self, rate_limit: Option<RateLimit>) {
+        if rate_limit.is_some() {
+            *self.lock() = rate_limit;
+        }
+    }
+
+    fn window(&self) -> Option<RateLimit> {
+        *self.lock()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<RateLimit>> {
+        self.window.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::rate_limiter::{RateLimitMode, RateLimiter};
+    use crate::dnsimple::RateLimit;
+    use crate::errors::DNSimpleError;
+    use std::time::{Duration, SystemTime};
+
+    fn rate_limit(remaining: u32, reset: SystemTime) -> Option<RateLimit> {
+        Some(RateLimit {
+            limit: 4000,
+            remaining,
+            reset,
+        })
+    }
+
+    fn in_seconds(seconds: u64) -> SystemTime {
+        SystemTime::now() + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn lets_requests_through_when_the_rate_limit_is_unknown() -> Result<(), DNSimpleError> {
+        let limiter = RateLimiter::new(10, RateLimitMode::Fail);
+
+        assert_eq!(None, limiter.acquire()?);
+        assert_eq!(None, limiter.remaining());
+
+        Ok(())
+    }
+
+    #[test]
+    fn reserves_requests_until_the_floor() -> Result<(), DNSimpleError> {
+        let limiter = RateLimiter::new(1, RateLimitMode::Fail);
+        limiter.update(rate_limit(3, in_seconds(60)));
+
+        assert_eq!(None, limiter.acquire()?);
+        assert_eq!(None, limiter.acquire()?);
+        assert_eq!(Some(1), limiter.remaining());
+        assert!(matches!(
+            limiter.acquire(),
+            Err(DNSimpleError::RateLimitWouldExceed { remaining: 1, .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn waits_until_the_reset_in_block_mode() -> Result<(), DNSimpleError> {
+        let limiter = RateLimiter::new(0, RateLimitMode::Block);
+        limiter.update(rate_limit(0, in_seconds(30)));
+
+        let wait = limiter.acquire()?.unwrap_or_default();
+
+        assert!(wait > Duration::from_secs(29) && wait <= Duration::from_secs(30));
+
+        Ok(())
+    }
+
+    #[test]
+    fn forgets_the_window_once_it_resets() -> Result<(), DNSimpleError> {
+        let limiter = RateLimiter::new(0, RateLimitMode::Fail);
+        limiter.update(rate_limit(0, SystemTime::UNIX_EPOCH));
+
+        assert_eq!(None, limiter.acquire()?);
+        assert_eq!(None, limiter.remaining());
+
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_the_window_when_a_response_has_no_rate_limit() {
+        let limiter = RateLimiter::new(0, RateLimitMode::Fail);
+        limiter.update(rate_limit(2, in_seconds(60)));
+        limiter.update(None);
+
+        assert_eq!(Some(2), limiter.remaining());
+    }
+
+    #[test]
+    fn shares_the_state_between_clones() {
+        let limiter = RateLimiter::new(0, RateLimitMode::Fail);
+        limiter.clone().update(rate_limit(5, in_seconds(60)));
+
+        assert_eq!(Some(5), limiter.remaining());
+    }
+}
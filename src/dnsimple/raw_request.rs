@@ -0,0 +1,147 @@
+use crate::dnsimple::{
+    build_response, payload_to_json, query_parameters, Client, DNSimpleResponse, RequestOptions,
+};
+use crate::errors::DNSimpleError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// The HTTP method of a `RawRequest`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// `GET`
+    Get,
+    /// `POST`
+    Post,
+    /// `PUT`
+    Put,
+    /// `PATCH`
+    Patch,
+    /// `DELETE`
+    Delete,
+}
+
+impl Method {
+    /// The method as sent in the request (i.e. `GET`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A request to any endpoint of the API, for the ones this crate does not wrap (yet)
+///
+/// The request goes through the same pipeline as the ones of the services: credentials,
+/// user agent, middlewares, rate limiter, retries, dry-run and errors. The `data` of the
+/// response is deserialized into `T` (use `serde_json::Value` to keep it untyped), and is
+/// `None` when the response has no body.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{new_client, Method};
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// #[derive(Deserialize)]
+/// struct Snapshot {
+///     id: u64,
+/// }
+///
+/// let client = new_client(true, String::from("AUTH_TOKEN"));
+/// let snapshot = client
+///     .request::<Snapshot>(Method::Post, "/1010/zones/example.com/snapshots")
+///     .query("notify", true)
+///     .json(json!({ "label": "before-migration" }))
+///     .send()
+///     .unwrap()
+///     .data
+///     .unwrap();
+/// ```
+pub struct RawRequest<'a, T> {
+    client: &'a Client,
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Result<Option<Value>, DNSimpleError>,
+    output: PhantomData<T>,
+}
+
+impl<'a, T: DeserializeOwned + 'static> RawRequest<'a, T> {
+    /// Adds a query parameter.
+    pub fn query(mut self, name: &str, value: impl ToString) -> RawRequest<'a, T> {
+        self.query.push((String::from(name), value.to_string()));
+        self
+    }
+
+    /// Adds the query parameters of the options (filters, sorting and pagination).
+    pub fn options(mut self, options: RequestOptions) -> RawRequest<'a, T> {
+        self.query.extend(query_parameters(Some(options)));
+        self
+    }
+
+    /// Sends the payload, serialized as JSON, as the body of the request
+    ///
+    /// If the payload cannot be serialized, `send` fails with `DNSimpleError::Deserialization`.
+    pub fn json(mut self, payload: impl Serialize) -> RawRequest<'a, T> {
+        self.body = payload_to_json(payload).map(Some);
+        self
+    }
+
+    /// Sends the request
+    ///
+    /// Fails like the calls of the services: with the error matching the status when the API
+    /// answers with an error, or with `DNSimpleError::Deserialization` when the body cannot be
+    /// read as `T`.
+    pub fn send(self) -> Result<DNSimpleResponse<T>, DNSimpleError> {
+        let mut request = self.client.build_request(self.method.as_str(), &self.path);
+        request.query = self.query;
+        request.body = self.body?;
+
+        let response = self.client.send(request)?;
+        let json = if response.body.trim().is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_str::<Value>(&response.body)
+                .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?
+        };
+
+        build_response(Client::rate_limit_of(&response), response.status, json)
+    }
+}
+
+impl Client {
+    /// Builds a request to the given endpoint, see `RawRequest`
+    ///
+    /// # Arguments
+    ///
+    /// `method`: The HTTP method
+    /// `path`: The path of the endpoint, relative to the versioned url (i.e. `/1010/zones`)
+    pub fn request<T: DeserializeOwned + 'static>(
+        &self,
+        method: Method,
+        path: &str,
+    ) -> RawRequest<'_, T> {
+        RawRequest {
+            client: self,
+            method,
+            path: String::from(path),
+            query: Vec::new(),
+            body: Ok(None),
+            output: PhantomData,
+        }
+    }
+}
@@ -1,6 +1,11 @@
+use crate::dnsimple::ids::{
+    AccountId, DomainRef, DomainRegistrationId, DomainRenewalId, DomainTransferId,
+};
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
 use crate::dnsimple::tlds::TldExtendedAttribute;
-use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents the domain check
@@ -75,9 +80,11 @@ pub struct DomainRegistration {
     /// True if the domain WHOIS privacy was requested.
     pub whois_privacy: bool,
     /// When the domain renewal was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the domain renewal was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Payload used to transfer a domain
@@ -119,9 +126,11 @@ pub struct DomainTransfer {
     /// The reason if transfer failed.
     pub status_description: Option<String>,
     /// When the domain renewal was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the domain renewal was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Payload to renew a domain
@@ -145,9 +154,11 @@ pub struct DomainRenewal {
     /// The state of the renewal.
     pub state: String,
     ///  When the domain renewal was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the domain renewal was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 struct DomainCheckEndpoint;
@@ -189,11 +200,12 @@ impl Endpoint for DomainRenewalEndpoint {
 /// The Registrar Service handles the domains registrations of the DNSimple API.
 ///
 /// See [API Documentation: registrar](https://developer.dnsimple.com/v2/registrar/)
-pub struct Registrar<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Registrar<C = Client> {
+    pub client: C,
 }
 
-impl Registrar<'_> {
+impl<C: ApiClient> Registrar<C> {
     /// Checks a domain name for availability.
     ///
     /// # Examples
@@ -211,10 +223,12 @@ impl Registrar<'_> {
     /// `domain`: The domain name
     pub fn check_domain(
         &self,
-        account_id: u64,
-        domain: &str,
-    ) -> Result<DNSimpleResponse<DomainCheck>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/check", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainCheck>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/registrar/domains/{}/check", account_id.into(), domain);
 
         self.client.get::<DomainCheckEndpoint>(&path, None)
     }
@@ -237,13 +251,15 @@ impl Registrar<'_> {
     #[deprecated(note = "please use `get_domain_prices` instead")]
     pub fn check_domain_premium_price(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         action: Option<String>,
-    ) -> Result<DNSimpleResponse<DomainPremiumPrice>, DNSimpleError> {
+    ) -> C::Result<'_, DNSimpleResponse<DomainPremiumPrice>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/registrar/domains/{}/premium_price?action={}",
-            account_id,
+            account_id.into(),
             domain,
             action.unwrap_or_else(|| "registration".into())
         );
@@ -268,10 +284,12 @@ impl Registrar<'_> {
     /// `domain`: The domain name
     pub fn get_domain_prices(
         &self,
-        account_id: u64,
-        domain: &str,
-    ) -> Result<DNSimpleResponse<DomainPrice>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/prices", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainPrice>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/registrar/domains/{}/prices", account_id.into(), domain);
 
         self.client.get::<DomainPricesEndpoint>(&path, None)
     }
@@ -294,13 +312,17 @@ impl Registrar<'_> {
     /// `domain_registration_id`: The domain registration id
     pub fn get_domain_registration(
         &self,
-        account_id: u64,
-        domain: &str,
-        domain_registration_id: u64,
-    ) -> Result<DNSimpleResponse<DomainRegistration>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_registration_id: impl Into<DomainRegistrationId>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainRegistration>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/registrar/domains/{}/registrations/{}",
-            account_id, domain, domain_registration_id
+            account_id.into(),
+            domain,
+            domain_registration_id.into()
         );
 
         self.client.get::<DomainRegistrationEndpoint>(&path, None)
@@ -324,13 +346,17 @@ impl Registrar<'_> {
     /// `domain_renewal_id`: The domain renewal id
     pub fn get_domain_renewal(
         &self,
-        account_id: u64,
-        domain: &str,
-        domain_renewal_id: u64,
-    ) -> Result<DNSimpleResponse<DomainRenewal>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_renewal_id: impl Into<DomainRenewalId>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainRenewal>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/registrar/domains/{}/renewals/{}",
-            account_id, domain, domain_renewal_id
+            account_id.into(),
+            domain,
+            domain_renewal_id.into()
         );
 
         self.client.get::<DomainRenewalEndpoint>(&path, None)
@@ -362,18 +388,20 @@ impl Registrar<'_> {
     /// `payload`: The `DomainRegistrationPayload` with the information needed to register the domain
     pub fn register_domain(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: DomainRegistrationPayload,
-    ) -> Result<DNSimpleResponse<DomainRegistration>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/registrations", account_id, domain);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<DomainRegistrationEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<DomainRegistration>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/registrations",
+            account_id.into(),
+            domain
+        );
+
+        self.client
+            .post::<DomainRegistrationEndpoint>(&path, payload)
     }
 
     /// Transfer a domain name from another domain registrar into DNSimple.
@@ -403,18 +431,19 @@ impl Registrar<'_> {
     /// `payload`: The `DomainTransferPayload` with the information needed to transfer the domain
     pub fn transfer_domain(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: DomainTransferPayload,
-    ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/transfers", account_id, domain);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<DomainTransferEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<DomainTransfer>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/transfers",
+            account_id.into(),
+            domain
+        );
+
+        self.client.post::<DomainTransferEndpoint>(&path, payload)
     }
 
     /// Retrieves the details of an existing domain transfer.
@@ -426,13 +455,17 @@ impl Registrar<'_> {
     /// `domain_transfer`: The domain transfer id
     pub fn get_domain_transfer(
         &self,
-        account_id: u64,
-        domain: String,
-        domain_transfer: u64,
-    ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_transfer: impl Into<DomainTransferId>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainTransfer>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/registrar/domains/{}/transfers/{}",
-            account_id, domain, domain_transfer
+            account_id.into(),
+            domain,
+            domain_transfer.into()
         );
 
         self.client.get::<DomainTransferEndpoint>(&path, None)
@@ -447,13 +480,17 @@ impl Registrar<'_> {
     /// `domain_transfer`: The domain transfer id
     pub fn cancel_domain_transfer(
         &self,
-        account_id: u64,
-        domain: String,
-        domain_transfer: u64,
-    ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_transfer: impl Into<DomainTransferId>,
+    ) -> C::Result<'_, DNSimpleResponse<DomainTransfer>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/registrar/domains/{}/transfers/{}",
-            account_id, domain, domain_transfer
+            account_id.into(),
+            domain,
+            domain_transfer.into()
         );
 
         self.client
@@ -469,18 +506,19 @@ impl Registrar<'_> {
     /// `payload`: The `DomainRenewalPayload` with the information needed to renew the domain
     pub fn renew_domain(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: DomainRenewalPayload,
-    ) -> Result<DNSimpleResponse<DomainRenewal>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/renewals", account_id, domain);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<DomainRenewalEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<DomainRenewal>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/renewals",
+            account_id.into(),
+            domain
+        );
+
+        self.client.post::<DomainRenewalEndpoint>(&path, payload)
     }
 
     /// Authorize a domain transfer out
@@ -491,12 +529,15 @@ impl Registrar<'_> {
     /// `domain`: The domain name
     pub fn transfer_domain_out(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/registrar/domains/{}/authorize_transfer_out",
-            account_id, domain
+            account_id.into(),
+            domain
         );
 
         self.client.empty_post(&path)
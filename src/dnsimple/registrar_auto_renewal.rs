@@ -1,8 +1,8 @@
+use crate::dnsimple::ids::{AccountId, DomainRef};
 use crate::dnsimple::registrar::Registrar;
-use crate::dnsimple::DNSimpleEmptyResponse;
-use crate::errors::DNSimpleError;
+use crate::dnsimple::{normalize, ApiClient, DNSimpleEmptyResponse};
 
-impl Registrar<'_> {
+impl<C: ApiClient> Registrar<C> {
     /// Enable domain auto-renewal
     ///
     /// # Arguments
@@ -11,10 +11,16 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub fn enable_domain_auto_renewal(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/auto_renewal", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/auto_renewal",
+            account_id.into(),
+            domain
+        );
 
         self.client.empty_put(&path)
     }
@@ -27,10 +33,16 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub fn disable_domain_auto_renewal(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/auto_renewal", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/auto_renewal",
+            account_id.into(),
+            domain
+        );
 
         self.client.delete(&path)
     }
@@ -1,6 +1,7 @@
+use crate::dnsimple::ids::{AccountId, DomainRef};
 use crate::dnsimple::registrar::Registrar;
-use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
+use crate::dnsimple::{normalize, ApiClient, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -22,9 +23,11 @@ pub struct VanityNameServer {
     /// The vanity name server IPv6.
     pub ipv6: String,
     /// When the vanity name server was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the vanity name server was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 struct DomainDelegationVanityEndpoint;
@@ -33,7 +36,7 @@ impl Endpoint for DomainDelegationVanityEndpoint {
     type Output = Vec<VanityNameServer>;
 }
 
-impl Registrar<'_> {
+impl<C: ApiClient> Registrar<C> {
     /// List name servers for the domain in the account.
     ///
     /// # Arguments
@@ -42,10 +45,16 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub fn get_domain_delegation(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleResponse<Vec<String>>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/delegation", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<String>>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/delegation",
+            account_id.into(),
+            domain
+        );
 
         self.client.get::<DomainDelegationEndpoint>(&path, None)
     }
@@ -59,11 +68,17 @@ impl Registrar<'_> {
     /// `server_names`: A list of name server names as strings
     pub fn change_domain_delegation(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         server_names: Vec<&str>,
-    ) -> Result<DNSimpleResponse<Vec<String>>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/delegation", account_id, domain);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<String>>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/delegation",
+            account_id.into(),
+            domain
+        );
 
         self.client
             .put::<DomainDelegationEndpoint>(&path, Value::from(server_names))
@@ -78,13 +93,16 @@ impl Registrar<'_> {
     /// `server_names`: A list of name server names as strings
     pub fn change_domain_delegation_to_vanity(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         server_names: Vec<&str>,
-    ) -> Result<DNSimpleResponse<Vec<VanityNameServer>>, DNSimpleError> {
+    ) -> C::Result<'_, DNSimpleResponse<Vec<VanityNameServer>>> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/registrar/domains/{}/delegation/vanity",
-            account_id, domain
+            account_id.into(),
+            domain
         );
 
         self.client
@@ -99,12 +117,15 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub fn change_domain_delegation_from_vanity(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
         let path = format!(
             "/{}/registrar/domains/{}/delegation/vanity",
-            account_id, domain
+            account_id.into(),
+            domain
         );
 
         self.client.delete(&path)
@@ -1,6 +1,7 @@
+use crate::dnsimple::ids::{AccountId, DomainRef};
 use crate::dnsimple::registrar::Registrar;
-use crate::dnsimple::{DNSimpleResponse, Endpoint};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::timestamps::{date, optional_date, timestamp, Date, Timestamp};
+use crate::dnsimple::{normalize, ApiClient, DNSimpleResponse, Endpoint};
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -12,13 +13,16 @@ pub struct WhoisPrivacy {
     /// The associated domain ID.
     pub domain_id: u64,
     /// The date the whois privacy will expire on.
-    pub expires_on: Option<String>,
+    #[serde(default, with = "optional_date")]
+    pub expires_on: Option<Date>,
     /// Whether the whois privacy is enabled for the domain.
     pub enabled: Option<bool>,
     /// When the whois privacy was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the whois privacy was created in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Represents the whois privacy renewal data
@@ -33,13 +37,16 @@ pub struct WhoisPrivacyRenewal {
     /// The WHOIS Privacy order state.
     pub state: String,
     /// The date the WHOIS Privacy will expire on.
-    pub expires_on: String,
+    #[serde(with = "date")]
+    pub expires_on: Date,
     /// Whether the WHOIS Privacy is enabled for the domain.
     pub enabled: bool,
     /// When the WHOIS Privacy was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the WHOIS Privacy was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 struct WhoisPrivacyEndpoint;
@@ -54,7 +61,7 @@ impl Endpoint for WhoisPrivacyRenewalEndpoint {
     type Output = WhoisPrivacyRenewal;
 }
 
-impl Registrar<'_> {
+impl<C: ApiClient> Registrar<C> {
     /// Retrieve the domain WHOIS privacy
     ///
     /// # Arguments
@@ -63,10 +70,16 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub fn get_whois_privacy(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/whois_privacy", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<WhoisPrivacy>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/whois_privacy",
+            account_id.into(),
+            domain
+        );
 
         self.client.get::<WhoisPrivacyEndpoint>(&path, None)
     }
@@ -79,10 +92,16 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub fn enable_whois_privacy(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/whois_privacy", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<WhoisPrivacy>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/whois_privacy",
+            account_id.into(),
+            domain
+        );
 
         self.client.put::<WhoisPrivacyEndpoint>(&path, Value::Null)
     }
@@ -95,10 +114,16 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub fn disable_whois_privacy(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/whois_privacy", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<WhoisPrivacy>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/whois_privacy",
+            account_id.into(),
+            domain
+        );
 
         self.client
             .delete_with_response::<WhoisPrivacyEndpoint>(&path)
@@ -112,10 +137,16 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub fn renew_whois_privacy(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleResponse<WhoisPrivacyRenewal>, DNSimpleError> {
-        let path = format!("/{}/registrar/domains/{}/whois_privacy", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<WhoisPrivacyRenewal>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/registrar/domains/{}/whois_privacy",
+            account_id.into(),
+            domain
+        );
 
         self.client
             .post::<WhoisPrivacyRenewalEndpoint>(&path, Value::Null)
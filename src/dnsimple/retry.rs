@@ -0,0 +1,259 @@
+use crate::errors::DNSimpleError;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Describes if and how the client re-issues requests failing with a transient error
+///
+/// Requests failing with `429 Too Many Requests`, `502 Bad Gateway`, `503 Service Unavailable`
+/// or `504 Gateway Timeout` are retried with an exponential backoff (with jitter). When the
+/// API throttles the client (`429`) the client waits until the `X-RateLimit-Reset` time instead,
+/// giving up right away if that is further away than `max_backoff`.
+///
+/// Only idempotent requests (`GET`, `PUT` and `DELETE`) are retried, unless
+/// `retry_non_idempotent` is set.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use dnsimple::dnsimple::{Client, RetryPolicy};
+///
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .retry_policy(RetryPolicy {
+///         max_attempts: 5,
+///         initial_backoff: Duration::from_millis(200),
+///         ..RetryPolicy::default()
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is sent (including the first one).
+    pub max_attempts: u32,
+    /// The time to wait before the first retry, doubled on every following one.
+    pub initial_backoff: Duration,
+    /// The maximum time to wait between two attempts.
+    pub max_backoff: Duration,
+    /// Also retry the requests that are not idempotent (`POST` and `PATCH`).
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries (the default of the clients).
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Returns how long to wait before sending the request again, or `None` if the
+    /// request should not be retried.
+    ///
+    /// # Arguments
+    ///
+    /// `method`: the HTTP method of the failed request
+    /// `attempt`: the number of times the request has been sent so far
+    /// `status`: the HTTP status of the failed request
+    /// `rate_limit_reset`: the `X-RateLimit-Reset` header of the failed request, if any
+    pub(crate) fn delay(
+        &self,
+        method: &str,
+        attempt: u32,
+        status: u16,
+        rate_limit_reset: Option<&str>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.retries(method) {
+            return None;
+        }
+
+        match status {
+            429 => match rate_limit_reset.and_then(until_reset) {
+                Some(wait) if wait <= self.max_backoff => Some(wait),
+                Some(_) => None,
+                None => Some(self.backoff(attempt)),
+            },
+            502..=504 => Some(self.backoff(attempt)),
+            _ => None,
+        }
+    }
+
+    /// Returns how long to wait before running a failed operation (of a `BulkExecutor`) again,
+    /// or `None` if it should not be retried.
+    ///
+    /// Throttled operations are always retried once the rate limit resets, as the API did
+    /// not process them. The other transient errors follow the same rules as the requests.
+    ///
+    /// # Arguments
+    ///
+    /// `attempt`: the number of times the operation has been run so far
+    /// `error`: the error the operation failed with
+    pub(crate) fn operation_delay(&self, attempt: u32, error: &DNSimpleError) -> Option<Duration> {
+        if attempt >= self.max_attempts || !error.is_retryable() {
+            return None;
+        }
+
+        match error {
+            DNSimpleError::TooManyRequests { .. } | DNSimpleError::RateLimitWouldExceed { .. } => {
+                match error.retry_after() {
+                    Some(wait) if wait <= self.max_backoff => Some(wait),
+                    Some(_) => None,
+                    None => Some(self.backoff(attempt)),
+                }
+            }
+            _ => match error.context() {
+                Some(context) if self.retries(&context.method) => Some(self.backoff(attempt)),
+                _ if self.retry_non_idempotent => Some(self.backoff(attempt)),
+                _ => None,
+            },
+        }
+    }
+
+    fn retries(&self, method: &str) -> bool {
+        match method {
+            "GET" | "PUT" | "DELETE" => true,
+            _ => self.retry_non_idempotent,
+        }
+    }
+
+    /// The exponential backoff for the given attempt, with a random jitter of up to 50%.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff);
+
+        exponential.mul_f64(0.5 + fastrand::f64() / 2.0)
+    }
+}
+
+/// The time left until the `X-RateLimit-Reset` (in Unix time) is reached.
+fn until_reset(reset: &str) -> Option<Duration> {
+    let reset = UNIX_EPOCH + Duration::from_secs(reset.parse().ok()?);
+
+    Some(
+        reset
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::retry::RetryPolicy;
+    use crate::errors::{DNSimpleError, ErrorContext};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(3),
+            retry_non_idempotent: false,
+        }
+    }
+
+    #[test]
+    fn retries_idempotent_requests_on_transient_errors() {
+        for status in [429, 502, 503, 504] {
+            assert!(policy().delay("GET", 1, status, None).is_some());
+            assert!(policy().delay("PUT", 1, status, None).is_some());
+            assert!(policy().delay("DELETE", 1, status, None).is_some());
+        }
+    }
+
+    #[test]
+    fn does_not_retry_other_errors() {
+        for status in [400, 401, 404, 500] {
+            assert_eq!(None, policy().delay("GET", 1, status, None));
+        }
+    }
+
+    #[test]
+    fn retries_non_idempotent_requests_only_when_enabled() {
+        assert_eq!(None, policy().delay("POST", 1, 503, None));
+        assert_eq!(None, policy().delay("PATCH", 1, 503, None));
+
+        let policy = RetryPolicy {
+            retry_non_idempotent: true,
+            ..policy()
+        };
+
+        assert!(policy.delay("POST", 1, 503, None).is_some());
+        assert!(policy.delay("PATCH", 1, 503, None).is_some());
+    }
+
+    #[test]
+    fn stops_after_max_attempts() {
+        assert!(policy().delay("GET", 3, 503, None).is_some());
+        assert_eq!(None, policy().delay("GET", 4, 503, None));
+        assert_eq!(None, RetryPolicy::none().delay("GET", 1, 503, None));
+    }
+
+    #[test]
+    fn backs_off_exponentially_up_to_max_backoff() {
+        let first = policy().delay("GET", 1, 503, None).unwrap_or_default();
+        let second = policy().delay("GET", 2, 503, None).unwrap_or_default();
+        let third = policy().delay("GET", 3, 503, None).unwrap_or_default();
+
+        assert!(first >= Duration::from_millis(500) && first <= Duration::from_secs(1));
+        assert!(second >= Duration::from_secs(1) && second <= Duration::from_secs(2));
+        assert!(third >= Duration::from_millis(1500) && third <= Duration::from_secs(3));
+    }
+
+    #[test]
+    fn waits_until_the_rate_limit_reset_on_too_many_requests() {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let soon = (now + 2).to_string();
+        let later = (now + 3600).to_string();
+        let past = (now - 10).to_string();
+
+        let wait = policy()
+            .delay("GET", 1, 429, Some(&soon))
+            .unwrap_or_default();
+        assert!(wait > Duration::from_secs(1) && wait <= Duration::from_secs(2));
+
+        assert_eq!(None, policy().delay("GET", 1, 429, Some(&later)));
+        assert_eq!(
+            Some(Duration::ZERO),
+            policy().delay("GET", 1, 429, Some(&past))
+        );
+    }
+
+    fn error(status: u16, method: &str) -> DNSimpleError {
+        DNSimpleError::from_context(
+            ErrorContext {
+                status,
+                request_id: None,
+                method: String::from(method),
+                path: String::from("/1010/zones/example.com/records"),
+                body: String::new(),
+                rate_limit: None,
+            },
+            "",
+        )
+    }
+
+    #[test]
+    fn retries_throttled_operations_whatever_their_method() {
+        assert!(policy().operation_delay(1, &error(429, "POST")).is_some());
+        assert!(policy().operation_delay(1, &error(503, "DELETE")).is_some());
+        assert_eq!(None, policy().operation_delay(1, &error(503, "POST")));
+        assert_eq!(None, policy().operation_delay(1, &error(400, "DELETE")));
+        assert_eq!(None, policy().operation_delay(4, &error(429, "POST")));
+    }
+}
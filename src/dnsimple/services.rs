@@ -1,5 +1,10 @@
-use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, DomainRef, ServiceRef};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, Paginate,
+    RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a service in DNSimple
@@ -20,9 +25,11 @@ pub struct Service {
     /// The default subdomain where the service will be applied.
     pub default_subdomain: Option<String>,
     /// When the service was created in DNSimple
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the service was last updated in DNSimple
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
     /// The array of settings to setup this service, if setup is required.
     pub settings: Vec<ServiceSetting>,
 }
@@ -44,6 +51,48 @@ pub struct ServiceSetting {
     pub password: bool,
 }
 
+/// The sorting policies of `list_services`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceSort {
+    IdAsc,
+    IdDesc,
+    SidAsc,
+    SidDesc,
+}
+
+impl ServiceSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceSort::IdAsc => "id:asc",
+            ServiceSort::IdDesc => "id:desc",
+            ServiceSort::SidAsc => "sid:asc",
+            ServiceSort::SidDesc => "sid:desc",
+        }
+    }
+}
+
+/// The sorting and pagination of the one-click services list
+///
+/// Converts into the `RequestOptions` taken by `list_services` and `list_services_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceListOptions {
+    /// The sorting policy.
+    pub sort: Option<ServiceSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<ServiceListOptions> for RequestOptions {
+    fn from(options: ServiceListOptions) -> Self {
+        RequestOptions::typed(
+            Vec::new(),
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct ServicesEndpoint;
 
 impl Endpoint for ServicesEndpoint {
@@ -59,16 +108,17 @@ impl Endpoint for ServiceEndpoint {
 /// The Services Service handles the domains services of the DNSimple API.
 ///
 /// See [API Documentation: services](https://developer.dnsimple.com/v2/services/)
-pub struct Services<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Services<C = Client> {
+    pub client: C,
 }
 
-impl Services<'_> {
+impl<C: ApiClient> Services<C> {
     /// List services
     pub fn list_services(
         &self,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Service>>, DNSimpleError> {
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Service>>> {
         let path = "/services";
 
         self.client.get::<ServicesEndpoint>(path, options)
@@ -78,8 +128,11 @@ impl Services<'_> {
     ///
     /// # Arguments
     /// `service`: The service name or id
-    pub fn get_service(&self, service: String) -> Result<DNSimpleResponse<Service>, DNSimpleError> {
-        let path = format!("/services/{}", service);
+    pub fn get_service(
+        &self,
+        service: impl Into<ServiceRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Service>> {
+        let path = format!("/services/{}", service.into());
 
         self.client.get::<ServiceEndpoint>(&path, None)
     }
@@ -91,11 +144,13 @@ impl Services<'_> {
     /// `domain`: The domain name or id
     pub fn applied_services(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Service>>, DNSimpleError> {
-        let path = format!("/{}/domains/{}/services", account_id, domain);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Service>>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/domains/{}/services", account_id.into(), domain);
 
         self.client.get::<ServicesEndpoint>(&path, options)
     }
@@ -108,11 +163,18 @@ impl Services<'_> {
     /// `service`: The service name or id
     pub fn apply_service(
         &self,
-        account_id: u64,
-        domain: String,
-        service: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/domains/{}/services/{}", account_id, domain, service);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        service: impl Into<ServiceRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/domains/{}/services/{}",
+            account_id.into(),
+            domain,
+            service.into()
+        );
 
         self.client.empty_post(&path)
     }
@@ -125,12 +187,32 @@ impl Services<'_> {
     /// `service`: The service name or id
     pub fn unapply_service(
         &self,
-        account_id: u64,
-        domain: String,
-        service: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/domains/{}/services/{}", account_id, domain, service);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        service: impl Into<ServiceRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/domains/{}/services/{}",
+            account_id.into(),
+            domain,
+            service.into()
+        );
 
         self.client.delete(&path)
     }
 }
+
+impl Services<Client> {
+    /// Lists all the services, fetching the pages lazily (see `list_services`)
+    ///
+    /// # Arguments
+    ///
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_services_iter(&self, options: Option<RequestOptions>) -> Paginator<Service> {
+        let service = self.clone();
+
+        Paginator::new(options, move |options| service.list_services(Some(options)))
+    }
+}
@@ -1,5 +1,10 @@
-use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, DomainRef, TemplateRecordId, TemplateRef};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, Paginate,
+    RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a template in DNSimple
@@ -16,9 +21,11 @@ pub struct Template {
     /// The template description.
     pub description: String,
     /// When the template was created in DNSimple
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the template was last updated in DNSimple
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Payload used when creating a template
@@ -51,9 +58,11 @@ pub struct TemplateRecord {
     #[serde(rename = "type")]
     pub record_type: String,
     /// When the template record was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the template record was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Payload used to create a template record
@@ -72,6 +81,102 @@ pub struct TemplateRecordPayload {
     pub priority: Option<u64>,
 }
 
+/// The sorting policies of `list_templates`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateSort {
+    IdAsc,
+    IdDesc,
+    NameAsc,
+    NameDesc,
+    SidAsc,
+    SidDesc,
+}
+
+impl TemplateSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemplateSort::IdAsc => "id:asc",
+            TemplateSort::IdDesc => "id:desc",
+            TemplateSort::NameAsc => "name:asc",
+            TemplateSort::NameDesc => "name:desc",
+            TemplateSort::SidAsc => "sid:asc",
+            TemplateSort::SidDesc => "sid:desc",
+        }
+    }
+}
+
+/// The sorting and pagination of the templates list
+///
+/// Converts into the `RequestOptions` taken by `list_templates` and `list_templates_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateListOptions {
+    /// The sorting policy.
+    pub sort: Option<TemplateSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<TemplateListOptions> for RequestOptions {
+    fn from(options: TemplateListOptions) -> Self {
+        RequestOptions::typed(
+            Vec::new(),
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
+/// The sorting policies of `list_template_records`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateRecordSort {
+    IdAsc,
+    IdDesc,
+    NameAsc,
+    NameDesc,
+    ContentAsc,
+    ContentDesc,
+    TypeAsc,
+    TypeDesc,
+}
+
+impl TemplateRecordSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemplateRecordSort::IdAsc => "id:asc",
+            TemplateRecordSort::IdDesc => "id:desc",
+            TemplateRecordSort::NameAsc => "name:asc",
+            TemplateRecordSort::NameDesc => "name:desc",
+            TemplateRecordSort::ContentAsc => "content:asc",
+            TemplateRecordSort::ContentDesc => "content:desc",
+            TemplateRecordSort::TypeAsc => "type:asc",
+            TemplateRecordSort::TypeDesc => "type:desc",
+        }
+    }
+}
+
+/// The sorting and pagination of the template records list
+///
+/// Converts into the `RequestOptions` taken by `list_template_records` and `list_template_records_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRecordListOptions {
+    /// The sorting policy.
+    pub sort: Option<TemplateRecordSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<TemplateRecordListOptions> for RequestOptions {
+    fn from(options: TemplateRecordListOptions) -> Self {
+        RequestOptions::typed(
+            Vec::new(),
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct TemplatesEndpoint;
 
 impl Endpoint for TemplatesEndpoint {
@@ -99,23 +204,24 @@ impl Endpoint for TemplateRecordEndpoint {
 /// The Templates Service handles the domains templates of the DNSimple API.
 ///
 /// See [API Documentation: templates](https://developer.dnsimple.com/v2/templates/)
-pub struct Templates<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Templates<C = Client> {
+    pub client: C,
 }
 
-impl Templates<'_> {
+impl<C: ApiClient> Templates<C> {
     /// List templates in the account.
     ///
     /// # Arguments
     ///
     /// `account_id`: The account id
-    /// `options`: The `RequestOptions` for sorting, etc.
+    /// `options`: The `RequestOptions` (i.e. a `TemplateListOptions` converted with `into()`)
     pub fn list_templates(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Template>>, DNSimpleError> {
-        let path = format!("/{}/templates", account_id);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Template>>> {
+        let path = format!("/{}/templates", account_id.into());
 
         self.client.get::<TemplatesEndpoint>(&path, options)
     }
@@ -128,17 +234,12 @@ impl Templates<'_> {
     /// `payload`: The `Template payload` with the information to create the template
     pub fn create_template(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         payload: TemplatePayload,
-    ) -> Result<DNSimpleResponse<Template>, DNSimpleError> {
-        let path = format!("/{}/templates", account_id);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<TemplateEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<Template>> {
+        let path = format!("/{}/templates", account_id.into());
+
+        self.client.post::<TemplateEndpoint>(&path, payload)
     }
 
     /// Retrieve a template in the account
@@ -149,10 +250,10 @@ impl Templates<'_> {
     /// `template`: The template name or id
     pub fn get_template(
         &self,
-        account_id: u64,
-        template: String,
-    ) -> Result<DNSimpleResponse<Template>, DNSimpleError> {
-        let path = format!("/{}/templates/{}", account_id, template);
+        account_id: impl Into<AccountId>,
+        template: impl Into<TemplateRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Template>> {
+        let path = format!("/{}/templates/{}", account_id.into(), template.into());
 
         self.client.get::<TemplateEndpoint>(&path, None)
     }
@@ -166,18 +267,13 @@ impl Templates<'_> {
     /// `payload`: The `Template payload` with the information to create the template
     pub fn update_template(
         &self,
-        account_id: u64,
-        template: String,
+        account_id: impl Into<AccountId>,
+        template: impl Into<TemplateRef>,
         payload: TemplatePayload,
-    ) -> Result<DNSimpleResponse<Template>, DNSimpleError> {
-        let path = format!("/{}/templates/{}", account_id, template);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.patch::<TemplateEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<Template>> {
+        let path = format!("/{}/templates/{}", account_id.into(), template.into());
+
+        self.client.patch::<TemplateEndpoint>(&path, payload)
     }
 
     /// Deletes a template from the account
@@ -187,10 +283,10 @@ impl Templates<'_> {
     /// `template`: The template name or id
     pub fn delete_template(
         &self,
-        account_id: u64,
-        template: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/templates/{}", account_id, template);
+        account_id: impl Into<AccountId>,
+        template: impl Into<TemplateRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let path = format!("/{}/templates/{}", account_id.into(), template.into());
 
         self.client.delete(&path)
     }
@@ -200,13 +296,18 @@ impl Templates<'_> {
     /// # Arguments
     /// `account_id`: The account id
     /// `template`: The template name or id
+    /// `options`: The `RequestOptions` (i.e. a `TemplateRecordListOptions` converted with `into()`)
     pub fn list_template_records(
         &self,
-        account_id: u64,
-        template: String,
+        account_id: impl Into<AccountId>,
+        template: impl Into<TemplateRef>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<TemplateRecord>>, DNSimpleError> {
-        let path = format!("/{}/templates/{}/records", account_id, template);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<TemplateRecord>>> {
+        let path = format!(
+            "/{}/templates/{}/records",
+            account_id.into(),
+            template.into()
+        );
 
         self.client.get::<TemplateRecordsEndpoint>(&path, options)
     }
@@ -219,18 +320,17 @@ impl Templates<'_> {
     /// `payload`: The `TemplateRecordPayload` with the information needed to create the template record
     pub fn create_template_record(
         &self,
-        account_id: u64,
-        template: String,
+        account_id: impl Into<AccountId>,
+        template: impl Into<TemplateRef>,
         payload: TemplateRecordPayload,
-    ) -> Result<DNSimpleResponse<TemplateRecord>, DNSimpleError> {
-        let path = format!("/{}/templates/{}/records", account_id, template);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<TemplateRecordEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<TemplateRecord>> {
+        let path = format!(
+            "/{}/templates/{}/records",
+            account_id.into(),
+            template.into()
+        );
+
+        self.client.post::<TemplateRecordEndpoint>(&path, payload)
     }
 
     /// Retrieve a template record
@@ -241,11 +341,16 @@ impl Templates<'_> {
     /// `record`: The record id
     pub fn get_template_record(
         &self,
-        account_id: u64,
-        template: String,
-        record: u64,
-    ) -> Result<DNSimpleResponse<TemplateRecord>, DNSimpleError> {
-        let path = format!("/{}/templates/{}/records/{}", account_id, template, record);
+        account_id: impl Into<AccountId>,
+        template: impl Into<TemplateRef>,
+        record: impl Into<TemplateRecordId>,
+    ) -> C::Result<'_, DNSimpleResponse<TemplateRecord>> {
+        let path = format!(
+            "/{}/templates/{}/records/{}",
+            account_id.into(),
+            template.into(),
+            record.into()
+        );
 
         self.client.get::<TemplateRecordEndpoint>(&path, None)
     }
@@ -258,11 +363,16 @@ impl Templates<'_> {
     /// `record`: The record id
     pub fn delete_template_record(
         &self,
-        account_id: u64,
-        template: String,
-        record: u64,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/templates/{}/records/{}", account_id, template, record);
+        account_id: impl Into<AccountId>,
+        template: impl Into<TemplateRef>,
+        record: impl Into<TemplateRecordId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let path = format!(
+            "/{}/templates/{}/records/{}",
+            account_id.into(),
+            template.into(),
+            record.into()
+        );
 
         self.client.delete(&path)
     }
@@ -275,12 +385,62 @@ impl Templates<'_> {
     /// `template`: The template id or short name
     pub fn apply_template(
         &self,
-        account_id: u64,
-        domain: String,
-        template: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/domains/{}/templates/{}", account_id, domain, template);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        template: impl Into<TemplateRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!(
+            "/{}/domains/{}/templates/{}",
+            account_id.into(),
+            domain,
+            template.into()
+        );
 
         self.client.empty_post(&path)
     }
 }
+
+impl Templates<Client> {
+    /// Lists all the templates, fetching the pages lazily (see `list_templates`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_templates_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Template> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+
+        Paginator::new(options, move |options| {
+            service.list_templates(account_id, Some(options))
+        })
+    }
+
+    /// Lists all the template records, fetching the pages lazily (see `list_template_records`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    /// `template`: The template name or id
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_template_records_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        template: impl Into<TemplateRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<TemplateRecord> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+        let template: TemplateRef = template.into();
+
+        Paginator::new(options, move |options| {
+            service.list_template_records(account_id, template.clone(), Some(options))
+        })
+    }
+}
@@ -0,0 +1,225 @@
+//! The types of the dates and times of the models
+//!
+//! By default they are kept as the `String`s sent by the API (i.e. `2015-04-23T07:40:03Z` and
+//! `2021-06-05`). Enable the `chrono` feature to get `chrono::DateTime<Utc>` and
+//! `chrono::NaiveDate` instead, or the `time` feature to get `time::OffsetDateTime` and
+//! `time::Date`. The two features change the same types, so they cannot be enabled together.
+
+#[cfg(all(feature = "chrono", feature = "time"))]
+compile_error!("the `chrono` and `time` features are mutually exclusive, enable only one of them");
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// A point in time (`created_at`, `updated_at`, `expires_at`...), in UTC.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+/// A calendar date (`expires_on`...).
+#[cfg(feature = "chrono")]
+pub type Date = chrono::NaiveDate;
+
+/// A point in time (`created_at`, `updated_at`, `expires_at`...), in UTC.
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub type Timestamp = time::OffsetDateTime;
+/// A calendar date (`expires_on`...).
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub type Date = time::Date;
+
+/// A point in time (`created_at`, `updated_at`, `expires_at`...), as sent by the API.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type Timestamp = String;
+/// A calendar date (`expires_on`...), as sent by the API.
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type Date = String;
+
+/// Parses a timestamp in the format of the API (RFC 3339, i.e. `2015-04-23T07:40:03Z`).
+pub fn parse_timestamp(value: &str) -> Result<Timestamp, String> {
+    #[cfg(feature = "chrono")]
+    {
+        chrono::DateTime::parse_from_rfc3339(value)
+            .map(|timestamp| timestamp.with_timezone(&chrono::Utc))
+            .map_err(|e| format!("invalid timestamp `{}`: {}", value, e))
+    }
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    {
+        time::OffsetDateTime::parse(value, &time::format_description::well_known::Rfc3339)
+            .map(|timestamp| timestamp.to_offset(time::UtcOffset::UTC))
+            .map_err(|e| format!("invalid timestamp `{}`: {}", value, e))
+    }
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    {
+        Ok(value.to_string())
+    }
+}
+
+/// Formats a timestamp like the API does (i.e. `2015-04-23T07:40:03Z`).
+pub fn format_timestamp(timestamp: &Timestamp) -> String {
+    #[cfg(feature = "chrono")]
+    {
+        timestamp.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+    }
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    {
+        timestamp
+            .to_offset(time::UtcOffset::UTC)
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_default()
+    }
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    {
+        timestamp.clone()
+    }
+}
+
+/// Parses a date in the format of the API (i.e. `2021-06-05`).
+pub fn parse_date(value: &str) -> Result<Date, String> {
+    #[cfg(feature = "chrono")]
+    {
+        chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|e| format!("invalid date `{}`: {}", value, e))
+    }
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    {
+        let invalid = |reason: String| format!("invalid date `{}`: {}", value, reason);
+        let parts: Vec<&str> = value.splitn(3, '-').collect();
+        let [year, month, day] = parts[..] else {
+            return Err(invalid(String::from("expected YYYY-MM-DD")));
+        };
+        let number = |part: &str| part.parse::<i32>().map_err(|e| invalid(e.to_string()));
+        let month = u8::try_from(number(month)?)
+            .map_err(|e| invalid(e.to_string()))
+            .and_then(|month| time::Month::try_from(month).map_err(|e| invalid(e.to_string())))?;
+        let day = u8::try_from(number(day)?).map_err(|e| invalid(e.to_string()))?;
+
+        time::Date::from_calendar_date(number(year)?, month, day)
+            .map_err(|e| invalid(e.to_string()))
+    }
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    {
+        Ok(value.to_string())
+    }
+}
+
+/// Formats a date like the API does (i.e. `2021-06-05`).
+pub fn format_date(date: &Date) -> String {
+    #[cfg(feature = "chrono")]
+    {
+        date.format("%Y-%m-%d").to_string()
+    }
+    #[cfg(all(feature = "time", not(feature = "chrono")))]
+    {
+        format!(
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            u8::from(date.month()),
+            date.day()
+        )
+    }
+    #[cfg(not(any(feature = "chrono", feature = "time")))]
+    {
+        date.clone()
+    }
+}
+
+/// (De)serializes a `Timestamp` field in the format of the API
+///
+/// To use in your own models: `#[serde(with = "dnsimple::dnsimple::timestamps::timestamp")]`.
+pub mod timestamp {
+    use super::*;
+
+    /// Serializes the value like the API does.
+    pub fn serialize<S: Serializer>(value: &Timestamp, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&format_timestamp(value))
+    }
+
+    /// Deserializes the value sent by the API.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Timestamp, D::Error> {
+        parse_timestamp(&String::deserialize(d)?).map_err(serde::de::Error::custom)
+    }
+}
+
+/// (De)serializes an `Option<Timestamp>` field in the format of the API (with `#[serde(default)]`).
+pub mod optional_timestamp {
+    use super::*;
+
+    /// Serializes the value like the API does.
+    pub fn serialize<S: Serializer>(value: &Option<Timestamp>, s: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => s.serialize_some(&format_timestamp(value)),
+            None => s.serialize_none(),
+        }
+    }
+
+    /// Deserializes the value sent by the API.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Timestamp>, D::Error> {
+        Option::<String>::deserialize(d)?
+            .map(|value| parse_timestamp(&value).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+/// (De)serializes a `Date` field in the format of the API.
+pub mod date {
+    use super::*;
+
+    /// Serializes the value like the API does.
+    pub fn serialize<S: Serializer>(value: &Date, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&format_date(value))
+    }
+
+    /// Deserializes the value sent by the API.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Date, D::Error> {
+        parse_date(&String::deserialize(d)?).map_err(serde::de::Error::custom)
+    }
+}
+
+/// (De)serializes an `Option<Date>` field in the format of the API (with `#[serde(default)]`).
+pub mod optional_date {
+    use super::*;
+
+    /// Serializes the value like the API does.
+    pub fn serialize<S: Serializer>(value: &Option<Date>, s: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => s.serialize_some(&format_date(value)),
+            None => s.serialize_none(),
+        }
+    }
+
+    /// Deserializes the value sent by the API.
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Date>, D::Error> {
+        Option::<String>::deserialize(d)?
+            .map(|value| parse_date(&value).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::timestamps::{format_date, format_timestamp, parse_date, parse_timestamp};
+
+    #[test]
+    fn round_trips_the_timestamps_of_the_api() {
+        let timestamp = parse_timestamp("2015-04-23T07:40:03Z");
+
+        assert_eq!(
+            Ok(String::from("2015-04-23T07:40:03Z")),
+            timestamp.map(|timestamp| format_timestamp(&timestamp))
+        );
+    }
+
+    #[test]
+    fn round_trips_the_dates_of_the_api() {
+        let date = parse_date("2021-06-05");
+
+        assert_eq!(
+            Ok(String::from("2021-06-05")),
+            date.map(|date| format_date(&date))
+        );
+    }
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    #[test]
+    fn rejects_invalid_values() {
+        assert!(parse_timestamp("yesterday").is_err());
+        assert!(parse_date("2021-13-05").is_err());
+    }
+}
@@ -1,5 +1,5 @@
-use crate::dnsimple::{Client, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::{ApiClient, Client, DNSimpleResponse, Endpoint, Paginate, RequestOptions};
 use serde::{Deserialize, Serialize};
 
 /// Represents a TLD in DNSimple
@@ -51,6 +51,44 @@ pub struct ExtendedAttributeOption {
     pub description: String,
 }
 
+/// The sorting policies of `list_tlds`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TldSort {
+    TldAsc,
+    TldDesc,
+}
+
+impl TldSort {
+    /// The value of the `sort` parameter (i.e. `tld:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TldSort::TldAsc => "tld:asc",
+            TldSort::TldDesc => "tld:desc",
+        }
+    }
+}
+
+/// The sorting and pagination of the TLDs list
+///
+/// Converts into the `RequestOptions` taken by `list_tlds` and `list_tlds_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct TldListOptions {
+    /// The sorting policy.
+    pub sort: Option<TldSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<TldListOptions> for RequestOptions {
+    fn from(options: TldListOptions) -> Self {
+        RequestOptions::typed(
+            Vec::new(),
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct ListTldsEndpoint;
 
 impl Endpoint for ListTldsEndpoint {
@@ -72,16 +110,17 @@ impl Endpoint for ListTldsExtendedAttributesEndpoint {
 /// The Tlds Service handles the tlds of the DNSimple API.
 ///
 /// See [API Documentation: tlds](https://developer.dnsimple.com/v2/tlds/)
-pub struct Tlds<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Tlds<C = Client> {
+    pub client: C,
 }
 
-impl Tlds<'_> {
+impl<C: ApiClient> Tlds<C> {
     /// Returns the list of TLDs supported for registration or transfer.
     pub fn list_tlds(
         &self,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Tld>>, DNSimpleError> {
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Tld>>> {
         let path = "/tlds";
 
         self.client.get::<ListTldsEndpoint>(path, options)
@@ -92,7 +131,7 @@ impl Tlds<'_> {
     /// # Attributes
     ///
     /// `tld`: The TLD name
-    pub fn get_tld(&self, tld: String) -> Result<DNSimpleResponse<Tld>, DNSimpleError> {
+    pub fn get_tld(&self, tld: String) -> C::Result<'_, DNSimpleResponse<Tld>> {
         let path = format!("/tlds/{}", tld);
 
         self.client.get::<TldEndpoint>(&path, None)
@@ -106,10 +145,23 @@ impl Tlds<'_> {
     pub fn get_tld_extended_attributes(
         &self,
         tld: String,
-    ) -> Result<DNSimpleResponse<Vec<TldExtendedAttribute>>, DNSimpleError> {
+    ) -> C::Result<'_, DNSimpleResponse<Vec<TldExtendedAttribute>>> {
         let path = format!("/tlds/{}/extended_attributes", tld);
 
         self.client
             .get::<ListTldsExtendedAttributesEndpoint>(&path, None)
     }
 }
+
+impl Tlds<Client> {
+    /// Lists all the TLDs, fetching the pages lazily (see `list_tlds`)
+    ///
+    /// # Arguments
+    ///
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_tlds_iter(&self, options: Option<RequestOptions>) -> Paginator<Tld> {
+        let service = self.clone();
+
+        Paginator::new(options, move |options| service.list_tlds(Some(options)))
+    }
+}
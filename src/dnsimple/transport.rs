@@ -0,0 +1,219 @@
+use crate::errors::{DNSimpleError, TransportErrorKind};
+use serde_json::Value;
+
+/// Describes a request to the DNSimple API, handed over to the `Transport` to be sent
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    /// The HTTP method (`GET`, `POST`, `PUT`, `PATCH` or `DELETE`).
+    pub method: String,
+    /// The full url of the request, without the query string.
+    pub url: String,
+    /// The query parameters, in the order they have to be sent.
+    pub query: Vec<(String, String)>,
+    /// The headers of the request (`User-Agent`, `Accept`, `Authorization`...).
+    pub headers: Vec<(String, String)>,
+    /// The JSON payload, if any.
+    pub body: Option<Value>,
+}
+
+impl HttpRequest {
+    /// Creates a request without query parameters, headers nor payload.
+    pub fn new(method: &str, url: &str) -> HttpRequest {
+        HttpRequest {
+            method: String::from(method),
+            url: String::from(url),
+            query: Vec::new(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Returns the value of the header with the given (case insensitive) name, if any.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// The path of the url (`https://api.dnsimple.com/v2/whoami` gives `/v2/whoami`).
+    pub fn path(&self) -> &str {
+        let without_scheme = self
+            .url
+            .split_once("://")
+            .map_or(self.url.as_str(), |(_, rest)| rest);
+
+        match without_scheme.find('/') {
+            Some(index) => &without_scheme[index..],
+            None => "/",
+        }
+    }
+}
+
+/// The response received by the `Transport`, whatever its status is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The reason phrase of the status (i.e. `Not Found`).
+    pub status_text: String,
+    /// The headers of the response.
+    pub headers: Vec<(String, String)>,
+    /// The raw body of the response.
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// Returns the value of the header with the given (case insensitive) name, if any.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Whether the status is not an error (below 400).
+    pub fn is_success(&self) -> bool {
+        self.status < 400
+    }
+}
+
+/// Sends the requests of a `Client` over the wire
+///
+/// By default the `Client` uses `UreqTransport`, implement this trait to plug in another
+/// HTTP stack, an in-memory fake for your tests or some instrumentation. A transport only
+/// fails when no response was received: responses with an error status are returned as they
+/// are, the `Client` maps them to a `DNSimpleError`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::{Client, HttpRequest, HttpResponse, Transport};
+/// use dnsimple::errors::DNSimpleError;
+///
+/// struct Offline;
+///
+/// impl Transport for Offline {
+///     fn send(&self, request: &HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+///         Ok(HttpResponse {
+///             status: 503,
+///             status_text: String::from("Service Unavailable"),
+///             headers: Vec::new(),
+///             body: String::new(),
+///         })
+///     }
+/// }
+///
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .transport(Offline)
+///     .build()
+///     .unwrap();
+/// ```
+pub trait Transport: Send + Sync {
+    /// Sends the request and returns the response received.
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, DNSimpleError>;
+}
+
+/// The default `Transport`, sending the requests with a `ureq::Agent`
+pub struct UreqTransport {
+    agent: ureq::Agent,
+}
+
+impl UreqTransport {
+    /// Creates a transport sending the requests with the given agent.
+    pub fn new(agent: ureq::Agent) -> UreqTransport {
+        UreqTransport { agent }
+    }
+}
+
+impl Default for UreqTransport {
+    fn default() -> Self {
+        UreqTransport::new(ureq::Agent::new())
+    }
+}
+
+impl Transport for UreqTransport {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+        let mut ureq_request = self.agent.request(&request.method, &request.url);
+
+        for (key, value) in &request.query {
+            ureq_request = ureq_request.query(key, value);
+        }
+        for (name, value) in &request.headers {
+            ureq_request = ureq_request.set(name, value);
+        }
+
+        let result = match request.body {
+            Some(ref body) => ureq_request.send_json(body),
+            None => ureq_request.call(),
+        };
+
+        match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => into_http_response(response),
+            Err(ureq::Error::Transport(transport)) => {
+                Err(DNSimpleError::parse_transport(transport))
+            }
+        }
+    }
+}
+
+/// Reads the whole response, failing with a `DNSimpleError::Transport` when its body cannot be
+/// read (connection lost, body over the 10 MB limit of ureq...).
+fn into_http_response(response: ureq::Response) -> Result<HttpResponse, DNSimpleError> {
+    let headers = response
+        .headers_names()
+        .into_iter()
+        .filter_map(|name| {
+            let value = response.header(&name)?.to_string();
+            Some((name, value))
+        })
+        .collect();
+
+    let status = response.status();
+    let status_text = response.status_text().to_string();
+    let body = response.into_string().map_err(|e| {
+        DNSimpleError::Transport(
+            format!("cannot read the response body: {}", e),
+            TransportErrorKind::Io,
+        )
+    })?;
+
+    Ok(HttpResponse {
+        status,
+        status_text,
+        headers,
+        body,
+    })
+}
+
+fn find_header<'h>(headers: &'h [(String, String)], name: &str) -> Option<&'h str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::transport::{HttpRequest, HttpResponse};
+
+    #[test]
+    fn finds_headers_case_insensitively() {
+        let response = HttpResponse {
+            status: 200,
+            status_text: String::from("OK"),
+            headers: vec![(String::from("x-ratelimit-limit"), String::from("4000"))],
+            body: String::new(),
+        };
+
+        assert_eq!(Some("4000"), response.header("X-RateLimit-Limit"));
+        assert_eq!(None, response.header("X-RateLimit-Remaining"));
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn keeps_the_path_of_the_url() {
+        let path = |url| HttpRequest::new("GET", url).path().to_string();
+
+        assert_eq!("/v2/whoami", path("https://api.dnsimple.com/v2/whoami"));
+        assert_eq!(
+            "/v2/1010/zones",
+            path("http://127.0.0.1:1234/v2/1010/zones")
+        );
+        assert_eq!("/", path("https://api.dnsimple.com"));
+    }
+}
@@ -1,6 +1,8 @@
+use crate::dnsimple::ids::{AccountId, DomainRef};
 use crate::dnsimple::registrar_name_servers::VanityNameServer;
-use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint,
+};
 use serde_json::Value;
 
 struct VanityNameServersEndpoint;
@@ -12,11 +14,12 @@ impl Endpoint for VanityNameServersEndpoint {
 /// The Vanity Name Servers Service handles the vanity name servers of the DNSimple API.
 ///
 /// See [API Documentation: vanity](https://developer.dnsimple.com/v2/vanity/)
-pub struct VanityNameServers<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct VanityNameServers<C = Client> {
+    pub client: C,
 }
 
-impl VanityNameServers<'_> {
+impl<C: ApiClient> VanityNameServers<C> {
     /// Enable vanity name servers
     ///
     /// # Arguments
@@ -24,10 +27,12 @@ impl VanityNameServers<'_> {
     /// `domain`: The domain name or id
     pub fn enable_vanity_name_servers(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleResponse<Vec<VanityNameServer>>, DNSimpleError> {
-        let path = format!("/{}/vanity/{}", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Vec<VanityNameServer>>> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/vanity/{}", account_id.into(), domain);
 
         self.client
             .put::<VanityNameServersEndpoint>(&path, Value::Null)
@@ -40,10 +45,12 @@ impl VanityNameServers<'_> {
     /// `domain`: The domain name or id
     pub fn disable_vanity_name_servers(
         &self,
-        account_id: u64,
-        domain: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/vanity/{}", account_id, domain);
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let domain = normalize!(self.client, domain);
+
+        let path = format!("/{}/vanity/{}", account_id.into(), domain);
 
         self.client.delete(&path)
     }
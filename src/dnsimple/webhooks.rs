@@ -1,5 +1,8 @@
-use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, WebhookId};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::{
+    ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a webhook
@@ -33,11 +36,12 @@ impl Endpoint for WebhookEndpoint {
 /// The Webhooks Service handles the webhooks of the DNSimple API.
 ///
 /// See [API Documentation: webhooks](https://developer.dnsimple.com/v2/webhooks/)
-pub struct Webhooks<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Webhooks<C = Client> {
+    pub client: C,
 }
 
-impl Webhooks<'_> {
+impl<C: ApiClient> Webhooks<C> {
     /// List webhooks in the account.
     ///
     /// # Arguments
@@ -45,10 +49,10 @@ impl Webhooks<'_> {
     /// `account_id`: The account id
     pub fn list_webhooks(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Webhook>>, DNSimpleError> {
-        let path = format!("/{}/webhooks", account_id);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Webhook>>> {
+        let path = format!("/{}/webhooks", account_id.into());
 
         self.client.get::<WebhooksEndpoint>(&path, options)
     }
@@ -61,18 +65,13 @@ impl Webhooks<'_> {
     /// `url`: The webhook url
     pub fn create_webhook(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         url: String,
-    ) -> Result<DNSimpleResponse<Webhook>, DNSimpleError> {
-        let path = format!("/{}/webhooks", account_id);
+    ) -> C::Result<'_, DNSimpleResponse<Webhook>> {
+        let path = format!("/{}/webhooks", account_id.into());
         let payload = WebhookPayload { url };
 
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<WebhookEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+        self.client.post::<WebhookEndpoint>(&path, payload)
     }
 
     /// Retrieve a webhook
@@ -82,10 +81,10 @@ impl Webhooks<'_> {
     /// `webhook`: The webhook id
     pub fn get_webhook(
         &self,
-        account_id: u64,
-        webhook: String,
-    ) -> Result<DNSimpleResponse<Webhook>, DNSimpleError> {
-        let path = format!("/{}/webhooks/{}", account_id, webhook);
+        account_id: impl Into<AccountId>,
+        webhook: impl Into<WebhookId>,
+    ) -> C::Result<'_, DNSimpleResponse<Webhook>> {
+        let path = format!("/{}/webhooks/{}", account_id.into(), webhook.into());
 
         self.client.get::<WebhookEndpoint>(&path, None)
     }
@@ -97,11 +96,32 @@ impl Webhooks<'_> {
     /// `webhook`: The webhook id
     pub fn delete_webhook(
         &self,
-        account_id: u64,
-        webhook: String,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/webhooks/{}", account_id, webhook);
+        account_id: impl Into<AccountId>,
+        webhook: impl Into<WebhookId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let path = format!("/{}/webhooks/{}", account_id.into(), webhook.into());
 
         self.client.delete(&path)
     }
 }
+
+impl Webhooks<Client> {
+    /// Lists all the webhooks, fetching the pages lazily (see `list_webhooks`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_webhooks_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Webhook> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+
+        Paginator::new(options, move |options| {
+            service.list_webhooks(account_id, Some(options))
+        })
+    }
+}
@@ -1,5 +1,9 @@
-use crate::dnsimple::{Client, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::ids::{AccountId, DomainRef};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleResponse, Endpoint, Paginate, RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -15,9 +19,11 @@ pub struct Zone {
     /// True if the zone is a reverse zone.
     pub reverse: bool,
     ///  When the zone was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     ///  When the zone was created in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Represents a zone file in DNSimple
@@ -34,6 +40,50 @@ pub struct ZoneDistribution {
     pub distributed: bool,
 }
 
+/// The sorting policies of `list_zones`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneSort {
+    IdAsc,
+    IdDesc,
+    NameAsc,
+    NameDesc,
+}
+
+impl ZoneSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZoneSort::IdAsc => "id:asc",
+            ZoneSort::IdDesc => "id:desc",
+            ZoneSort::NameAsc => "name:asc",
+            ZoneSort::NameDesc => "name:desc",
+        }
+    }
+}
+
+/// The filters, sorting and pagination of the zones list
+///
+/// Converts into the `RequestOptions` taken by `list_zones` and `list_zones_iter`.
+#[derive(Debug, Clone, Default)]
+pub struct ZoneListOptions {
+    /// Only the zones whose name contains this string.
+    pub name_like: Option<String>,
+    /// The sorting policy.
+    pub sort: Option<ZoneSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<ZoneListOptions> for RequestOptions {
+    fn from(options: ZoneListOptions) -> Self {
+        RequestOptions::typed(
+            vec![("name_like", options.name_like)],
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct ListZonesEndpoint;
 
 impl Endpoint for ListZonesEndpoint {
@@ -73,23 +123,26 @@ impl Endpoint for DistributionEndpoint {
 /// The Zones Service handles the zone distribution of the DNSimple API.
 ///
 /// See [API Documentation: zones](https://developer.dnsimple.com/v2/zones/)
-pub struct Zones<'a> {
-    pub client: &'a Client,
+#[derive(Clone)]
+pub struct Zones<C = Client> {
+    pub client: C,
 }
 
-impl Zones<'_> {
+impl<C: ApiClient> Zones<C> {
     /// Activates DNS resolution for the zone in the account.
     ///
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `zone_name`: The zone name
+    /// `zone`: The zone name
     pub fn activate_dns(
         &self,
-        account_id: u64,
-        zone_name: &str,
-    ) -> Result<DNSimpleResponse<Zone>, DNSimpleError> {
-        let path = format!("/{}/zones/{}/activation", account_id, zone_name);
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Zone>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!("/{}/zones/{}/activation", account_id.into(), zone);
 
         self.client.put::<ActivateDnsEndpoint>(&path, Value::Null)
     }
@@ -99,16 +152,18 @@ impl Zones<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `zone_name`: The zone name
+    /// `zone`: The zone name
     pub fn deactivate_dns(
         &self,
-        account_id: u64,
-        zone_name: &str,
-    ) -> Result<DNSimpleResponse<Zone>, DNSimpleError> {
-        let path = format!("/{}/zones/{}/activation", account_id, zone_name);
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Zone>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!("/{}/zones/{}/activation", account_id.into(), zone);
 
         self.client
-            .delete_with_response::<ActivateDnsEndpoint>(&path)
+            .delete_with_response::<DeactivateDnsEndpoint>(&path)
     }
 
     /// Lists the zones in the account.
@@ -116,12 +171,13 @@ impl Zones<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
+    /// `options`: The `RequestOptions` (i.e. a `ZoneListOptions` converted with `into()`)
     pub fn list_zones(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<Zone>>, DNSimpleError> {
-        let path = format!("/{}/zones", account_id);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<Zone>>> {
+        let path = format!("/{}/zones", account_id.into());
 
         self.client.get::<ListZonesEndpoint>(&path, options)
     }
@@ -134,10 +190,12 @@ impl Zones<'_> {
     /// `zone`: The zone name
     pub fn get_zone(
         &self,
-        account_id: u64,
-        zone: &str,
-    ) -> Result<DNSimpleResponse<Zone>, DNSimpleError> {
-        let path = format!("/{}/zones/{}", account_id, zone);
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<Zone>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!("/{}/zones/{}", account_id.into(), zone);
 
         self.client.get::<ZoneEndpoint>(&path, None)
     }
@@ -150,10 +208,12 @@ impl Zones<'_> {
     /// `zone`: The zone name
     pub fn get_zone_file(
         &self,
-        account_id: u64,
-        zone: &str,
-    ) -> Result<DNSimpleResponse<ZoneFile>, DNSimpleError> {
-        let path = format!("/{}/zones/{}/file", account_id, zone);
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneFile>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!("/{}/zones/{}/file", account_id.into(), zone);
 
         self.client.get::<ZoneFileEndpoint>(&path, None)
     }
@@ -166,11 +226,34 @@ impl Zones<'_> {
     /// `zone`: The zone name
     pub fn check_zone_distribution(
         &self,
-        account_id: u64,
-        zone: &str,
-    ) -> Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError> {
-        let path = format!("/{}/zones/{}/distribution", account_id, zone);
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneDistribution>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!("/{}/zones/{}/distribution", account_id.into(), zone);
 
         self.client.get::<DistributionEndpoint>(&path, None)
     }
 }
+
+impl Zones<Client> {
+    /// Lists all the zones, fetching the pages lazily (see `list_zones`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_zones_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<Zone> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+
+        Paginator::new(options, move |options| {
+            service.list_zones(account_id, Some(options))
+        })
+    }
+}
@@ -1,7 +1,12 @@
+use crate::dnsimple::ids::{AccountId, DomainRef, ZoneRecordId};
+use crate::dnsimple::paginator::Paginator;
+use crate::dnsimple::timestamps::{timestamp, Timestamp};
 use crate::dnsimple::zones::DistributionEndpoint;
 use crate::dnsimple::zones::{ZoneDistribution, Zones};
-use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
-use crate::errors::DNSimpleError;
+use crate::dnsimple::{
+    normalize, ApiClient, Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, Paginate,
+    RequestOptions,
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a zone record in DNSimple
@@ -29,9 +34,11 @@ pub struct ZoneRecord {
     /// True if this is a system record created by DNSimple. System records are read-only.
     pub system_record: bool,
     /// When the record was created in DNSimple.
-    pub created_at: String,
+    #[serde(with = "timestamp")]
+    pub created_at: Timestamp,
     /// When the record was last updated in DNSimple.
-    pub updated_at: String,
+    #[serde(with = "timestamp")]
+    pub updated_at: Timestamp,
 }
 
 /// Represents the payload to be send to create a zone record
@@ -67,6 +74,87 @@ pub struct ZoneRecordUpdatePayload {
     pub regions: Option<Vec<String>>,
 }
 
+/// The sorting policies of `list_zone_records`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneRecordSort {
+    IdAsc,
+    IdDesc,
+    NameAsc,
+    NameDesc,
+    ContentAsc,
+    ContentDesc,
+    TypeAsc,
+    TypeDesc,
+    TtlAsc,
+    TtlDesc,
+}
+
+impl ZoneRecordSort {
+    /// The value of the `sort` parameter (i.e. `id:asc`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZoneRecordSort::IdAsc => "id:asc",
+            ZoneRecordSort::IdDesc => "id:desc",
+            ZoneRecordSort::NameAsc => "name:asc",
+            ZoneRecordSort::NameDesc => "name:desc",
+            ZoneRecordSort::ContentAsc => "content:asc",
+            ZoneRecordSort::ContentDesc => "content:desc",
+            ZoneRecordSort::TypeAsc => "type:asc",
+            ZoneRecordSort::TypeDesc => "type:desc",
+            ZoneRecordSort::TtlAsc => "ttl:asc",
+            ZoneRecordSort::TtlDesc => "ttl:desc",
+        }
+    }
+}
+
+/// The filters, sorting and pagination of the zone records list
+///
+/// Converts into the `RequestOptions` taken by `list_zone_records` and `list_zone_records_iter`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+/// use dnsimple::dnsimple::zones_records::{ZoneRecordListOptions, ZoneRecordSort};
+///
+/// let client = new_client(true, String::from("AUTH_TOKEN"));
+/// let options = ZoneRecordListOptions {
+///     name_like: Some(String::from("www")),
+///     record_type: Some(String::from("A")),
+///     sort: Some(ZoneRecordSort::TtlDesc),
+///     ..Default::default()
+/// };
+///
+/// let records = client.zones().list_zone_records(1234, "example.com", Some(options.into())).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ZoneRecordListOptions {
+    /// Only the records with this exact name (`""` for the apex).
+    pub name: Option<String>,
+    /// Only the records whose name contains this string.
+    pub name_like: Option<String>,
+    /// Only the records of this type (i.e. `MX`).
+    pub record_type: Option<String>,
+    /// The sorting policy.
+    pub sort: Option<ZoneRecordSort>,
+    /// The pagination instructions.
+    pub paginate: Option<Paginate>,
+}
+
+impl From<ZoneRecordListOptions> for RequestOptions {
+    fn from(options: ZoneRecordListOptions) -> Self {
+        RequestOptions::typed(
+            vec![
+                ("name", options.name),
+                ("name_like", options.name_like),
+                ("type", options.record_type),
+            ],
+            options.sort.map(|sort| sort.as_str()),
+            options.paginate,
+        )
+    }
+}
+
 struct ZoneRecordsEndpoint;
 
 impl Endpoint for ZoneRecordsEndpoint {
@@ -79,20 +167,23 @@ impl Endpoint for ZoneRecordEndpoint {
     type Output = ZoneRecord;
 }
 
-impl Zones<'_> {
+impl<C: ApiClient> Zones<C> {
     /// List zone records
     ///
     /// # Arguments
     ///
     /// `account_id`: The account ID
     /// `zone`: The zone name
+    /// `options`: The `RequestOptions` (i.e. a `ZoneRecordListOptions` converted with `into()`)
     pub fn list_zone_records(
         &self,
-        account_id: u64,
-        zone: &str,
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
         options: Option<RequestOptions>,
-    ) -> Result<DNSimpleResponse<Vec<ZoneRecord>>, DNSimpleError> {
-        let path = format!("/{}/zones/{}/records", account_id, zone);
+    ) -> C::Result<'_, DNSimpleResponse<Vec<ZoneRecord>>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!("/{}/zones/{}/records", account_id.into(), zone);
 
         self.client.get::<ZoneRecordsEndpoint>(&path, options)
     }
@@ -106,18 +197,15 @@ impl Zones<'_> {
     /// `payload`: The `ZoneRecordPayload` with the information to create the zone record
     pub fn create_zone_record(
         &self,
-        account_id: u64,
-        zone: &str,
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
         payload: ZoneRecordPayload,
-    ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError> {
-        let path = format!("/{}/zones/{}/records", account_id, zone);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.post::<ZoneRecordEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<ZoneRecord>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!("/{}/zones/{}/records", account_id.into(), zone);
+
+        self.client.post::<ZoneRecordEndpoint>(&path, payload)
     }
 
     /// Retrieve a zone record
@@ -129,11 +217,18 @@ impl Zones<'_> {
     /// `record`: The record id
     pub fn get_zone_record(
         &self,
-        account_id: u64,
-        zone: &str,
-        record: u64,
-    ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError> {
-        let path = format!("/{}/zones/{}/records/{}", account_id, zone, record);
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+        record: impl Into<ZoneRecordId>,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneRecord>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!(
+            "/{}/zones/{}/records/{}",
+            account_id.into(),
+            zone,
+            record.into()
+        );
 
         self.client.get::<ZoneRecordEndpoint>(&path, None)
     }
@@ -148,19 +243,21 @@ impl Zones<'_> {
     /// `payload`: The `ZoneRecordUpdatePayload` with the information to create the zone record
     pub fn update_zone_record(
         &self,
-        account_id: u64,
-        zone: &str,
-        record: u64,
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+        record: impl Into<ZoneRecordId>,
         payload: ZoneRecordUpdatePayload,
-    ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError> {
-        let path = format!("/{}/zones/{}/records/{}", account_id, zone, record);
-
-        match serde_json::to_value(payload) {
-            Ok(json) => self.client.patch::<ZoneRecordEndpoint>(&path, json),
-            Err(_) => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot deserialize json payload",
-            ))),
-        }
+    ) -> C::Result<'_, DNSimpleResponse<ZoneRecord>> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!(
+            "/{}/zones/{}/records/{}",
+            account_id.into(),
+            zone,
+            record.into()
+        );
+
+        self.client.patch::<ZoneRecordEndpoint>(&path, payload)
     }
 
     /// Delete a zone record
@@ -172,11 +269,18 @@ impl Zones<'_> {
     /// `record`: The record id
     pub fn delete_zone_record(
         &self,
-        account_id: u64,
-        zone: &str,
-        record: u64,
-    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/zones/{}/records/{}", account_id, zone, record);
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+        record: impl Into<ZoneRecordId>,
+    ) -> C::Result<'_, DNSimpleEmptyResponse> {
+        let zone = normalize!(self.client, zone);
+
+        let path = format!(
+            "/{}/zones/{}/records/{}",
+            account_id.into(),
+            zone,
+            record.into()
+        );
 
         self.client.delete(&path)
     }
@@ -190,15 +294,43 @@ impl Zones<'_> {
     /// `record`: The record id
     pub fn check_zone_record_distribution(
         &self,
-        account_id: u64,
-        zone: &str,
-        record: u64,
-    ) -> Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError> {
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+        record: impl Into<ZoneRecordId>,
+    ) -> C::Result<'_, DNSimpleResponse<ZoneDistribution>> {
+        let zone = normalize!(self.client, zone);
+
         let path = format!(
             "/{}/zones/{}/records/{}/distribution",
-            account_id, zone, record
+            account_id.into(),
+            zone,
+            record.into()
         );
 
         self.client.get::<DistributionEndpoint>(&path, None)
     }
 }
+
+impl Zones<Client> {
+    /// Lists all the zone records, fetching the pages lazily (see `list_zone_records`)
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `zone`: The zone name
+    /// `options`: The `RequestOptions`, the filters and sorting are sent with every page
+    pub fn list_zone_records_iter(
+        &self,
+        account_id: impl Into<AccountId>,
+        zone: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> Paginator<ZoneRecord> {
+        let service = self.clone();
+        let account_id: AccountId = account_id.into();
+        let zone: DomainRef = zone.into();
+
+        Paginator::new(options, move |options| {
+            service.list_zone_records(account_id, zone.clone(), Some(options))
+        })
+    }
+}
@@ -1,92 +1,394 @@
+use crate::dnsimple::{
+    HttpRequest, HttpResponse, RateLimit, RATE_LIMIT_LIMIT, RATE_LIMIT_REMAINING, RATE_LIMIT_RESET,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
+use std::error::Error as _;
+use std::fmt;
+use std::io;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
-use ureq::{Response, Transport};
+use ureq::Transport;
+
+/// The `X-Request-Id` header identifying a request in the DNSimple logs.
+pub(crate) const REQUEST_ID: &str = "X-Request-Id";
+
+/// Describes the failed request behind an error returned by the API
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The `X-Request-Id` header of the response, to give to the DNSimple support.
+    pub request_id: Option<String>,
+    /// The HTTP method of the request.
+    pub method: String,
+    /// The path of the request (i.e. `/v2/1010/zones`).
+    pub path: String,
+    /// The raw body of the response.
+    pub body: String,
+    /// The rate limit reported with the error (`None` if the headers are missing).
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// The validation errors of a payload, by field name (i.e. `content` for `ZoneRecordPayload::content`)
+///
+/// Parsed from the `errors` object of a `400 Bad Request` or a `422 Unprocessable Entity`
+/// response, see `DNSimpleError::validation_errors`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+/// use dnsimple::dnsimple::zones_records::ZoneRecordPayload;
+///
+/// let client = new_client(true, String::from("AUTH_TOKEN"));
+/// let payload = ZoneRecordPayload {
+///     name: String::from("www"),
+///     record_type: String::from("A"),
+///     content: String::new(),
+///     ttl: None,
+///     priority: None,
+///     regions: None,
+/// };
+///
+/// if let Err(error) = client.zones().create_zone_record(1234, "example.com", payload) {
+///     let errors = error.validation_errors().unwrap_or_default();
+///
+///     for message in errors.field("content") {
+///         println!("content {}", message);
+///     }
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct ValidationErrors {
+    errors: BTreeMap<String, Vec<String>>,
+}
+
+impl ValidationErrors {
+    /// The messages of the given field, empty if it is valid.
+    pub fn field(&self, name: &str) -> &[String] {
+        self.errors.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// The invalid fields with their messages, sorted by field name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.errors
+            .iter()
+            .map(|(field, messages)| (field.as_str(), messages.as_slice()))
+    }
+
+    /// The number of invalid fields.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Whether no field is invalid.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// What failed while sending a request, recorded with a `DNSimpleError::Transport` error
+///
+/// Set from the `ureq::ErrorKind` of the failure (or from the `reqwest::Error` of the async
+/// client), `DNSimpleError::is_retryable` classifies the transport errors on it.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// The url (or its scheme) could not be understood.
+    InvalidUrl,
+    /// The DNS lookup of the host failed.
+    Dns,
+    /// The connection to the server failed.
+    ConnectionFailed,
+    /// The connection failed while sending the request or reading the response.
+    Io,
+    /// The request did not complete in time.
+    Timeout,
+    /// The proxy is invalid, or refused the connection.
+    Proxy,
+    /// The response could not be understood (bad status line or header, too many redirects).
+    BadResponse,
+    /// The cassette could not be written, or has no interaction for the request.
+    Cassette,
+    /// Any other failure.
+    Other,
+}
+
+impl TransportErrorKind {
+    /// Whether the failure is caused by the network, sending the same request later may
+    /// succeed (DNS and connection failures, I/O errors and timeouts).
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::Dns | Self::ConnectionFailed | Self::Io | Self::Timeout
+        )
+    }
+}
+
+impl From<ureq::ErrorKind> for TransportErrorKind {
+    fn from(kind: ureq::ErrorKind) -> Self {
+        match kind {
+            ureq::ErrorKind::InvalidUrl
+            | ureq::ErrorKind::UnknownScheme
+            | ureq::ErrorKind::InsecureRequestHttpsOnly => Self::InvalidUrl,
+            ureq::ErrorKind::Dns => Self::Dns,
+            ureq::ErrorKind::ConnectionFailed => Self::ConnectionFailed,
+            ureq::ErrorKind::Io => Self::Io,
+            ureq::ErrorKind::InvalidProxyUrl
+            | ureq::ErrorKind::ProxyConnect
+            | ureq::ErrorKind::ProxyUnauthorized => Self::Proxy,
+            ureq::ErrorKind::TooManyRedirects
+            | ureq::ErrorKind::BadStatus
+            | ureq::ErrorKind::BadHeader => Self::BadResponse,
+            ureq::ErrorKind::HTTP => Self::Other,
+        }
+    }
+}
+
+impl fmt::Display for TransportErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::InvalidUrl => "Bad URL",
+            Self::Dns => "Dns Failed",
+            Self::ConnectionFailed => "Connection Failed",
+            Self::Io => "Network Error",
+            Self::Timeout => "Network Timeout",
+            Self::Proxy => "Proxy Error",
+            Self::BadResponse => "Bad Response",
+            Self::Cassette => "Cassette Error",
+            Self::Other => "Transport Error",
+        };
+
+        f.write_str(name)
+    }
+}
 
 /// Represents the possible errors thrown while interacting with the DNSimple API
+///
+/// The errors returned by the API (all the variants with a `context`) carry an `ErrorContext`
+/// describing the failed request, also available with `DNSimpleError::context`.
 #[derive(Error, Deserialize, Serialize, Debug, PartialEq, Eq)]
 pub enum DNSimpleError {
     #[error("Authentication failed")]
-    Unauthorized,
+    Unauthorized { context: Box<ErrorContext> },
     #[error("Bad Gateway")]
-    BadGateway,
+    BadGateway { context: Box<ErrorContext> },
     #[error("{message}")]
     BadRequest {
         message: String,
         attribute_errors: Option<Value>,
+        context: Box<ErrorContext>,
+    },
+    #[error("{message}")]
+    Conflict {
+        message: String,
+        context: Box<ErrorContext>,
+    },
+    #[error("{message}")]
+    Forbidden {
+        message: String,
+        context: Box<ErrorContext>,
+    },
+    #[error("{message}")]
+    GatewayTimeout {
+        message: String,
+        context: Box<ErrorContext>,
     },
-    #[error("{0}")]
-    GatewayTimeout(String),
+    #[error("Internal Server Error")]
+    InternalServerError { context: Box<ErrorContext> },
     #[error("Method not Allowed")]
-    MethodNotAllowed,
-    #[error("{0}")]
-    NotFound(String),
+    MethodNotAllowed { context: Box<ErrorContext> },
+    #[error("{message}")]
+    NotFound {
+        message: String,
+        context: Box<ErrorContext>,
+    },
     #[error("Your account is not subscribed or not in good standing")]
-    PaymentRequired,
-    #[error("{0}")]
-    PreconditionRequired(String),
+    PaymentRequired { context: Box<ErrorContext> },
+    #[error("{message}")]
+    PreconditionRequired {
+        message: String,
+        context: Box<ErrorContext>,
+    },
     #[error("Service Unavailable")]
-    ServiceUnavailable,
+    ServiceUnavailable { context: Box<ErrorContext> },
     #[error("You exceeded the allowed number of requests per hour and your request has temporarily been throttled.")]
-    TooManyRequests,
+    TooManyRequests { context: Box<ErrorContext> },
+    #[error("{message}")]
+    UnprocessableEntity {
+        message: String,
+        attribute_errors: Option<Value>,
+        context: Box<ErrorContext>,
+    },
+    #[error("Unexpected HTTP status {}({status_text})", .context.status)]
+    UnexpectedStatus {
+        status_text: String,
+        context: Box<ErrorContext>,
+    },
+    #[error("Sending the request would exceed the rate limit ({remaining} requests remaining until it resets)")]
+    RateLimitWouldExceed { remaining: u32, reset: SystemTime },
     #[error("Transport Error - {0}({1})")]
-    Transport(String, String),
+    Transport(String, TransportErrorKind),
     #[error("Deserialization Error {0}")]
     Deserialization(String),
+    #[error("Invalid Configuration - {0}")]
+    Configuration(String),
+    #[error("Invalid domain name `{name}` - {reason}")]
+    InvalidDomain { name: String, reason: String },
 }
 
 impl DNSimpleError {
-    pub fn parse_response(code: u16, response: Response) -> DNSimpleError {
-        match code {
-            400 => Self::bad_request(response),
-            401 => Self::Unauthorized,
-            402 => Self::PaymentRequired,
-            404 => Self::not_found(response),
-            405 => Self::MethodNotAllowed,
-            428 => Self::precondition_required(response),
-            429 => Self::TooManyRequests,
-            502 => Self::BadGateway,
-            503 => Self::ServiceUnavailable,
-            504 => Self::gateway_timeout(response),
-            _ => Self::Transport(
-                response.status().to_string(),
-                response.status_text().to_string(),
+    /// Maps a response with an error status to a `DNSimpleError`, with the context of the request.
+    pub fn parse_response(request: &HttpRequest, response: &HttpResponse) -> DNSimpleError {
+        let context = ErrorContext {
+            status: response.status,
+            request_id: response.header(REQUEST_ID).map(String::from),
+            method: request.method.clone(),
+            path: request.path().to_string(),
+            body: response.body.clone(),
+            rate_limit: RateLimit::from_headers(
+                response.header(RATE_LIMIT_LIMIT),
+                response.header(RATE_LIMIT_REMAINING),
+                response.header(RATE_LIMIT_RESET),
             ),
-        }
+        };
+
+        Self::from_context(context, &response.status_text)
     }
 
     pub fn parse_transport(transport: Transport) -> DNSimpleError {
-        Self::Transport(transport.to_string(), transport.kind().to_string())
+        let kind = match transport.kind() {
+            ureq::ErrorKind::Io if is_timeout(&transport) => TransportErrorKind::Timeout,
+            kind => TransportErrorKind::from(kind),
+        };
+
+        Self::Transport(transport.to_string(), kind)
     }
 
-    fn bad_request(response: Response) -> DNSimpleError {
-        match Self::response_to_json(response) {
-            Ok(json) => Self::BadRequest {
-                message: Self::message_in(&json),
-                attribute_errors: Some(json["errors"].clone()),
-            },
-            Err(error) => error,
+    /// The context of the failed request, for the errors returned by the API.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::Unauthorized { context }
+            | Self::BadGateway { context }
+            | Self::BadRequest { context, .. }
+            | Self::Conflict { context, .. }
+            | Self::Forbidden { context, .. }
+            | Self::GatewayTimeout { context, .. }
+            | Self::InternalServerError { context }
+            | Self::MethodNotAllowed { context }
+            | Self::NotFound { context, .. }
+            | Self::PaymentRequired { context }
+            | Self::PreconditionRequired { context, .. }
+            | Self::ServiceUnavailable { context }
+            | Self::TooManyRequests { context }
+            | Self::UnprocessableEntity { context, .. }
+            | Self::UnexpectedStatus { context, .. } => Some(context),
+            Self::RateLimitWouldExceed { .. }
+            | Self::Transport(..)
+            | Self::Deserialization(_)
+            | Self::Configuration(_)
+            | Self::InvalidDomain { .. } => None,
+        }
+    }
+
+    /// Whether the error is transient, sending the same request later may succeed
+    ///
+    /// That is the case of the statuses retried by the `RetryPolicy` (429, 502, 503 and 504),
+    /// of the requests held back by the `RateLimiter` and of the transport failures caused by
+    /// the network (connection failures, I/O errors and timeouts). The other transport errors
+    /// (invalid url or proxy, missing cassette interaction...) would fail the same way again.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::TooManyRequests { .. }
+            | Self::BadGateway { .. }
+            | Self::ServiceUnavailable { .. }
+            | Self::GatewayTimeout { .. }
+            | Self::RateLimitWouldExceed { .. } => true,
+            Self::Transport(_, kind) => kind.is_transient(),
+            _ => false,
         }
     }
 
-    fn gateway_timeout(response: Response) -> DNSimpleError {
-        match Self::response_to_json(response) {
-            Ok(json) => Self::GatewayTimeout(Self::message_in(&json)),
-            Err(error) => error,
+    /// Whether the API answered with a 4xx status, the request has to be changed to succeed
+    /// (`TooManyRequests` aside, as it only has to be sent later).
+    pub fn is_client_error(&self) -> bool {
+        self.context().map_or(false, |context| {
+            (400..500).contains(&context.status) && context.status != 429
+        })
+    }
+
+    /// How long to wait before sending the request again, when the rate limit was (or would
+    /// have been) exceeded.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::TooManyRequests { context } => {
+                context.rate_limit.as_ref().map(RateLimit::time_until_reset)
+            }
+            Self::RateLimitWouldExceed { reset, .. } => Some(
+                reset
+                    .duration_since(SystemTime::now())
+                    .unwrap_or(Duration::ZERO),
+            ),
+            _ => None,
         }
     }
 
-    fn not_found(response: Response) -> DNSimpleError {
-        match Self::response_to_json(response) {
-            Ok(json) => Self::NotFound(Self::message_in(&json)),
-            Err(error) => error,
+    /// The validation errors of a `BadRequest` or an `UnprocessableEntity` error, if the
+    /// response listed any.
+    pub fn validation_errors(&self) -> Option<ValidationErrors> {
+        match self {
+            Self::BadRequest {
+                attribute_errors: Some(errors),
+                ..
+            }
+            | Self::UnprocessableEntity {
+                attribute_errors: Some(errors),
+                ..
+            } => serde_json::from_value(errors.clone()).ok(),
+            _ => None,
         }
     }
 
-    fn precondition_required(response: Response) -> DNSimpleError {
-        match Self::response_to_json(response) {
-            Ok(json) => Self::PreconditionRequired(Self::message_in(&json)),
-            Err(error) => error,
+    /// Maps an unsuccessful HTTP status (the body returned with it being in the context) to a
+    /// `DNSimpleError`.
+    pub(crate) fn from_context(context: ErrorContext, status_text: &str) -> DNSimpleError {
+        let json = serde_json::from_str::<Value>(&context.body).ok();
+        let message = match json {
+            Some(ref json) => Self::message_in(json),
+            None => status_text.to_string(),
+        };
+        let attribute_errors = json.map(|json| json["errors"].clone());
+        let context = Box::new(context);
+
+        match context.status {
+            400 => Self::BadRequest {
+                message,
+                attribute_errors,
+                context,
+            },
+            401 => Self::Unauthorized { context },
+            402 => Self::PaymentRequired { context },
+            403 => Self::Forbidden { message, context },
+            404 => Self::NotFound { message, context },
+            405 => Self::MethodNotAllowed { context },
+            409 => Self::Conflict { message, context },
+            422 => Self::UnprocessableEntity {
+                message,
+                attribute_errors,
+                context,
+            },
+            428 => Self::PreconditionRequired { message, context },
+            429 => Self::TooManyRequests { context },
+            500 => Self::InternalServerError { context },
+            502 => Self::BadGateway { context },
+            503 => Self::ServiceUnavailable { context },
+            504 => Self::GatewayTimeout { message, context },
+            _ => Self::UnexpectedStatus {
+                status_text: status_text.to_string(),
+                context,
+            },
         }
     }
 
@@ -96,11 +398,17 @@ impl DNSimpleError {
             Some(json_string) => json_string.to_string(),
         }
     }
+}
 
-    fn response_to_json(response: Response) -> Result<Value, DNSimpleError> {
-        match response.into_json::<Value>() {
-            Ok(value) => Ok(value),
-            Err(error) => Err(DNSimpleError::Deserialization(error.to_string())),
-        }
-    }
+/// Whether the I/O error behind a ureq transport error is a read or write timeout.
+fn is_timeout(transport: &Transport) -> bool {
+    transport
+        .source()
+        .and_then(|source| source.downcast_ref::<io::Error>())
+        .map_or(false, |error| {
+            matches!(
+                error.kind(),
+                io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock
+            )
+        })
 }
@@ -11,3 +11,5 @@
 #![deny(clippy::expect_used, clippy::panic, clippy::unwrap_used)]
 pub mod dnsimple;
 pub mod errors;
+#[cfg(feature = "testing")]
+pub mod testing;
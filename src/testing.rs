@@ -0,0 +1,977 @@
+//! A stateful, in-memory fake of the DNSimple API to test your code end-to-end.
+//!
+//! Enable the `testing` feature to use it. The `FakeServer` listens on a local port and keeps
+//! accounts, zones, zone records, domains, contacts, templates (and their records) and webhooks
+//! in memory: a created item is returned by the list and get endpoints until it is deleted.
+//! Invalid payloads are answered with a `400 Bad Request` shaped like the API validation errors.
+//!
+//! Pagination is supported (`page` and `per_page`), filters and sorting are ignored.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use dnsimple::testing::FakeServer;
+//!
+//! let server = FakeServer::start().unwrap();
+//! let client = server.client();
+//!
+//! client.domains().create_domain(FakeServer::ACCOUNT_ID, String::from("example.com")).unwrap();
+//!
+//! let zones = client.zones().list_zones(FakeServer::ACCOUNT_ID, None).unwrap().data.unwrap();
+//! assert_eq!("example.com", zones[0].name);
+//! ```
+
+use crate::dnsimple::{new_client, Client};
+use crate::errors::{DNSimpleError, TransportErrorKind};
+use serde_json::{json, Map, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The record types accepted for zone and template records.
+const RECORD_TYPES: [&str; 15] = [
+    "A", "AAAA", "ALIAS", "CAA", "CNAME", "DNSKEY", "DS", "HINFO", "MX", "NAPTR", "NS", "POOL",
+    "PTR", "SRV", "TXT",
+];
+
+/// The fields a contact cannot be created without.
+const CONTACT_REQUIRED: [&str; 9] = [
+    "first_name",
+    "last_name",
+    "address1",
+    "city",
+    "state_province",
+    "postal_code",
+    "country",
+    "email",
+    "phone",
+];
+
+const CONTACT_OPTIONAL: [&str; 5] = ["label", "job_title", "organization_name", "fax", "address2"];
+
+const DEFAULT_TTL: u64 = 3600;
+const DEFAULT_PER_PAGE: usize = 30;
+const RATE_LIMIT: u64 = 2400;
+
+/// A fake DNSimple API listening on `127.0.0.1`
+///
+/// The server is stopped when dropped.
+pub struct FakeServer {
+    address: SocketAddr,
+    state: Arc<Mutex<State>>,
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl FakeServer {
+    /// The id of the account the server starts with (the one returned by `whoami`).
+    pub const ACCOUNT_ID: u64 = 1010;
+
+    /// Starts a server on a free port, with a single account (`ACCOUNT_ID`).
+    pub fn start() -> Result<FakeServer, DNSimpleError> {
+        let listener = TcpListener::bind("127.0.0.1:0").map_err(server_error)?;
+        let address = listener.local_addr().map_err(server_error)?;
+        let state = Arc::new(Mutex::new(State::new()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread = {
+            let state = Arc::clone(&state);
+            let running = Arc::clone(&running);
+
+            thread::spawn(move || {
+                let mut connections: Vec<JoinHandle<()>> = Vec::new();
+
+                for stream in listener.incoming() {
+                    if !running.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        // A slow or idle connection must not hold back the other clients.
+                        let state = Arc::clone(&state);
+                        connections.retain(|connection| !connection.is_finished());
+                        connections.push(thread::spawn(move || serve(stream, &state)));
+                    }
+                }
+
+                for connection in connections {
+                    let _ = connection.join();
+                }
+            })
+        };
+
+        Ok(FakeServer {
+            address,
+            state,
+            running,
+            thread: Some(thread),
+        })
+    }
+
+    /// The url of the server, to give to `Client::set_base_url`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Creates a `Client` sending its requests to this server.
+    pub fn client(&self) -> Client {
+        let mut client = new_client(false, String::from("fake-token"));
+        client.set_base_url(&self.url());
+        client
+    }
+
+    /// Adds an account with the given email, returning its id.
+    pub fn add_account(&self, email: &str) -> u64 {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let id = state.next_id();
+        let now = timestamp(SystemTime::now());
+
+        state.accounts.push(json!({
+            "id": id,
+            "email": email,
+            "plan_identifier": "teams-v1-monthly",
+            "created_at": now,
+            "updated_at": now,
+        }));
+        id
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+        // Wakes the listener up so it notices it has to stop.
+        let _ = TcpStream::connect(self.address);
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn server_error(error: std::io::Error) -> DNSimpleError {
+    DNSimpleError::Transport(error.to_string(), TransportErrorKind::Other)
+}
+
+struct State {
+    last_id: u64,
+    requests: u64,
+    accounts: Vec<Value>,
+    zones: Vec<Value>,
+    zone_records: Vec<Value>,
+    domains: Vec<Value>,
+    contacts: Vec<Value>,
+    templates: Vec<Value>,
+    template_records: Vec<Value>,
+    webhooks: Vec<Value>,
+}
+
+impl State {
+    fn new() -> State {
+        let now = timestamp(SystemTime::now());
+
+        State {
+            last_id: 0,
+            requests: 0,
+            accounts: vec![json!({
+                "id": FakeServer::ACCOUNT_ID,
+                "email": "john@example.com",
+                "plan_identifier": "teams-v1-monthly",
+                "created_at": now,
+                "updated_at": now,
+            })],
+            zones: Vec::new(),
+            zone_records: Vec::new(),
+            domains: Vec::new(),
+            contacts: Vec::new(),
+            templates: Vec::new(),
+            template_records: Vec::new(),
+            webhooks: Vec::new(),
+        }
+    }
+
+    fn next_id(&mut self) -> u64 {
+        self.last_id += 1;
+        self.last_id
+    }
+}
+
+struct Request {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    authorized: bool,
+    body: Value,
+}
+
+struct Reply {
+    status: u16,
+    body: Option<Value>,
+}
+
+impl Reply {
+    fn ok(data: Value) -> Reply {
+        Reply {
+            status: 200,
+            body: Some(json!({ "data": data })),
+        }
+    }
+
+    fn created(data: Value) -> Reply {
+        Reply {
+            status: 201,
+            body: Some(json!({ "data": data })),
+        }
+    }
+
+    fn no_content() -> Reply {
+        Reply {
+            status: 204,
+            body: None,
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Reply {
+        Reply {
+            status,
+            body: Some(json!({ "message": message })),
+        }
+    }
+
+    fn not_found(kind: &str, id: &str) -> Reply {
+        Reply::error(404, &format!("{} `{}` not found", kind, id))
+    }
+}
+
+/// Collects the errors of a payload, the way the API reports them.
+#[derive(Default)]
+struct Validation {
+    errors: Map<String, Value>,
+}
+
+impl Validation {
+    fn error(&mut self, field: &str, message: &str) {
+        if let Value::Array(messages) = self
+            .errors
+            .entry(field)
+            .or_insert_with(|| Value::Array(Vec::new()))
+        {
+            messages.push(Value::from(message));
+        }
+    }
+
+    fn required(&mut self, payload: &Value, field: &str) {
+        if text(payload, field).is_empty() {
+            self.error(field, "can't be blank");
+        }
+    }
+
+    fn record_type(&mut self, payload: &Value) {
+        if !RECORD_TYPES.contains(&text(payload, "type").as_str()) {
+            self.error("type", "is not included in the list");
+        }
+    }
+
+    fn into_reply(self) -> Option<Reply> {
+        if self.errors.is_empty() {
+            return None;
+        }
+
+        Some(Reply {
+            status: 400,
+            body: Some(json!({ "message": "Validation failed", "errors": self.errors })),
+        })
+    }
+}
+
+fn serve(stream: TcpStream, state: &Mutex<State>) {
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+
+    let reply = match read_request(&stream) {
+        Some(request) => {
+            let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+            state.requests += 1;
+
+            let reply = if request.authorized {
+                route(&mut state, &request)
+            } else {
+                Reply::error(401, "Authentication failed")
+            };
+            (reply, state.requests)
+        }
+        None => (Reply::error(400, "Malformed request"), 0),
+    };
+
+    write_reply(stream, reply.0, reply.1);
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?.to_string();
+
+    let mut content_length = 0;
+    let mut authorized = false;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        let (name, value) = header.split_once(':')?;
+        if name.eq_ignore_ascii_case("Content-Length") {
+            content_length = value.trim().parse().ok()?;
+        } else if name.eq_ignore_ascii_case("Authorization") {
+            authorized = !value.trim().is_empty();
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+
+    let (path, query) = target.split_once('?').unwrap_or((target.as_str(), ""));
+    let query = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+
+    Some(Request {
+        method,
+        path: path.to_string(),
+        query,
+        authorized,
+        body: serde_json::from_slice(&body).unwrap_or(Value::Null),
+    })
+}
+
+fn write_reply(mut stream: TcpStream, reply: Reply, requests: u64) {
+    let body = reply.body.map(|body| body.to_string()).unwrap_or_default();
+    let reset = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+        + 3600;
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\nX-RateLimit-Limit: {}\r\nX-RateLimit-Remaining: {}\r\nX-RateLimit-Reset: {}\r\nConnection: close\r\n\r\n{}",
+        reply.status,
+        status_text(reply.status),
+        body.len(),
+        RATE_LIMIT,
+        RATE_LIMIT - requests % RATE_LIMIT,
+        reset,
+        body
+    );
+
+    let _ = stream.write_all(response.as_bytes());
+    let _ = stream.flush();
+}
+
+/// The reason phrase of a status, empty (as allowed by RFC 7230) for the unknown ones.
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        402 => "Payment Required",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        422 => "Unprocessable Entity",
+        428 => "Precondition Required",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "",
+    }
+}
+
+fn route(state: &mut State, request: &Request) -> Reply {
+    let segments: Vec<&str> = request
+        .path
+        .trim_start_matches("/v2")
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let method = request.method.as_str();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["whoami"]) => Reply::ok(json!({
+            "account": state.accounts.first(),
+            "user": null,
+        })),
+        ("GET", ["accounts"]) => Reply::ok(Value::Array(state.accounts.clone())),
+        (_, [account, rest @ ..]) => {
+            let account_id = match account.parse::<u64>() {
+                Ok(id) if state.accounts.iter().any(|a| a["id"] == id) => id,
+                _ => return Reply::not_found("Account", account),
+            };
+
+            match (method, rest) {
+                ("GET", ["zones"]) => list(&state.zones, account_id, request),
+                ("GET", ["zones", zone]) => {
+                    get(&state.zones, account_id, "Zone", zone, &["id", "name"])
+                }
+                (_, ["zones", zone, "records", ..]) => {
+                    zone_records(state, account_id, zone, &rest[3..], request)
+                }
+                ("GET", ["domains"]) => list(&state.domains, account_id, request),
+                ("POST", ["domains"]) => create_domain(state, account_id, &request.body),
+                ("GET", ["domains", domain]) => get(
+                    &state.domains,
+                    account_id,
+                    "Domain",
+                    domain,
+                    &["id", "name"],
+                ),
+                ("DELETE", ["domains", domain]) => delete_domain(state, account_id, domain),
+                ("GET", ["contacts"]) => list(&state.contacts, account_id, request),
+                ("POST", ["contacts"]) => create_contact(state, account_id, &request.body),
+                ("GET", ["contacts", contact]) => {
+                    get(&state.contacts, account_id, "Contact", contact, &["id"])
+                }
+                ("PATCH", ["contacts", contact]) => {
+                    update_contact(state, account_id, contact, &request.body)
+                }
+                ("DELETE", ["contacts", contact]) => {
+                    delete(&mut state.contacts, account_id, "Contact", contact, &["id"])
+                }
+                (_, ["templates", ..]) => templates(state, account_id, &rest[1..], request),
+                ("GET", ["webhooks"]) => list(&state.webhooks, account_id, request),
+                ("POST", ["webhooks"]) => create_webhook(state, account_id, &request.body),
+                ("GET", ["webhooks", webhook]) => {
+                    get(&state.webhooks, account_id, "Webhook", webhook, &["id"])
+                }
+                ("DELETE", ["webhooks", webhook]) => {
+                    delete(&mut state.webhooks, account_id, "Webhook", webhook, &["id"])
+                }
+                _ => Reply::error(404, "Not found"),
+            }
+        }
+        _ => Reply::error(404, "Not found"),
+    }
+}
+
+fn zone_records(
+    state: &mut State,
+    account_id: u64,
+    zone: &str,
+    rest: &[&str],
+    request: &Request,
+) -> Reply {
+    let zone_name = match find(&state.zones, account_id, zone, &["id", "name"]) {
+        Some(index) => text(&state.zones[index], "name"),
+        None => return Reply::not_found("Zone", zone),
+    };
+    let in_zone = |record: &Value| record["zone_id"] == zone_name.as_str();
+
+    match (request.method.as_str(), rest) {
+        ("GET", []) => {
+            let records: Vec<Value> = state
+                .zone_records
+                .iter()
+                .filter(|record| in_zone(record))
+                .cloned()
+                .collect();
+            list(&records, account_id, request)
+        }
+        ("POST", []) => {
+            let payload = &request.body;
+            let mut validation = Validation::default();
+            validation.record_type(payload);
+            validation.required(payload, "content");
+            if let Some(reply) = validation.into_reply() {
+                return reply;
+            }
+
+            let now = timestamp(SystemTime::now());
+            let record = json!({
+                "id": state.next_id(),
+                "account_id": account_id,
+                "zone_id": zone_name,
+                "parent_id": null,
+                "name": text(payload, "name"),
+                "content": text(payload, "content"),
+                "ttl": payload["ttl"].as_u64().unwrap_or(DEFAULT_TTL),
+                "priority": payload["priority"],
+                "type": text(payload, "type"),
+                "regions": payload.get("regions").filter(|r| !r.is_null()).cloned().unwrap_or_else(|| json!(["global"])),
+                "system_record": false,
+                "created_at": now,
+                "updated_at": now,
+            });
+            state.zone_records.push(record.clone());
+            Reply::created(record)
+        }
+        (method, [record]) => {
+            let index = state.zone_records.iter().position(|r| {
+                in_zone(r) && r["account_id"] == account_id && is_id(&r["id"], record)
+            });
+            let index = match index {
+                Some(index) => index,
+                None => return Reply::not_found("Record", record),
+            };
+
+            match method {
+                "GET" => Reply::ok(state.zone_records[index].clone()),
+                "PATCH" => {
+                    let payload = &request.body;
+                    let mut validation = Validation::default();
+                    if payload.get("content").map_or(false, |c| c == "") {
+                        validation.error("content", "can't be blank");
+                    }
+                    if let Some(reply) = validation.into_reply() {
+                        return reply;
+                    }
+
+                    let record = &mut state.zone_records[index];
+                    merge(
+                        record,
+                        payload,
+                        &["name", "content", "ttl", "priority", "regions"],
+                    );
+                    Reply::ok(record.clone())
+                }
+                "DELETE" => {
+                    state.zone_records.remove(index);
+                    Reply::no_content()
+                }
+                _ => Reply::error(404, "Not found"),
+            }
+        }
+        _ => Reply::error(404, "Not found"),
+    }
+}
+
+fn create_domain(state: &mut State, account_id: u64, payload: &Value) -> Reply {
+    let name = text(payload, "name").to_lowercase();
+    let mut validation = Validation::default();
+    if name.is_empty() {
+        validation.error("name", "can't be blank");
+    } else if !name.contains('.') || name.starts_with('.') || name.ends_with('.') {
+        validation.error("name", "is an invalid domain");
+    } else if find(&state.domains, account_id, &name, &["name"]).is_some() {
+        validation.error("name", "has already been taken");
+    }
+    if let Some(reply) = validation.into_reply() {
+        return reply;
+    }
+
+    let now = timestamp(SystemTime::now());
+    let domain = json!({
+        "id": state.next_id(),
+        "account_id": account_id,
+        "registrant_id": null,
+        "name": name,
+        "unicode_name": name,
+        "state": "hosted",
+        "auto_renew": false,
+        "private_whois": false,
+        "expires_on": null,
+        "expires_at": null,
+        "created_at": now,
+        "updated_at": now,
+    });
+    state.domains.push(domain.clone());
+
+    if find(&state.zones, account_id, &name, &["name"]).is_none() {
+        let zone = json!({
+            "id": state.next_id(),
+            "account_id": account_id,
+            "name": name,
+            "reverse": false,
+            "created_at": now,
+            "updated_at": now,
+        });
+        state.zones.push(zone);
+    }
+
+    Reply::created(domain)
+}
+
+/// Deleting a domain also deletes its zone and the records of the zone.
+fn delete_domain(state: &mut State, account_id: u64, domain: &str) -> Reply {
+    let index = match find(&state.domains, account_id, domain, &["id", "name"]) {
+        Some(index) => index,
+        None => return Reply::not_found("Domain", domain),
+    };
+    let name = text(&state.domains.remove(index), "name");
+
+    state
+        .zones
+        .retain(|zone| !(zone["account_id"] == account_id && zone["name"] == name.as_str()));
+    state.zone_records.retain(|record| {
+        !(record["account_id"] == account_id && record["zone_id"] == name.as_str())
+    });
+
+    Reply::no_content()
+}
+
+fn create_contact(state: &mut State, account_id: u64, payload: &Value) -> Reply {
+    let mut validation = Validation::default();
+    for field in CONTACT_REQUIRED {
+        validation.required(payload, field);
+    }
+    validate_contact(&mut validation, payload, true);
+    if let Some(reply) = validation.into_reply() {
+        return reply;
+    }
+
+    let now = timestamp(SystemTime::now());
+    let mut contact = json!({
+        "id": state.next_id(),
+        "account_id": account_id,
+        "created_at": now,
+        "updated_at": now,
+    });
+    for field in CONTACT_REQUIRED.iter().chain(CONTACT_OPTIONAL.iter()) {
+        contact[*field] = Value::from(text(payload, field));
+    }
+
+    state.contacts.push(contact.clone());
+    Reply::created(contact)
+}
+
+fn update_contact(state: &mut State, account_id: u64, contact: &str, payload: &Value) -> Reply {
+    let index = match find(&state.contacts, account_id, contact, &["id"]) {
+        Some(index) => index,
+        None => return Reply::not_found("Contact", contact),
+    };
+
+    let mut validation = Validation::default();
+    for field in CONTACT_REQUIRED {
+        if payload.get(field).map_or(false, |value| value == "") {
+            validation.error(field, "can't be blank");
+        }
+    }
+    validate_contact(&mut validation, payload, false);
+    if let Some(reply) = validation.into_reply() {
+        return reply;
+    }
+
+    let contact = &mut state.contacts[index];
+    let fields: Vec<&str> = CONTACT_REQUIRED
+        .iter()
+        .chain(CONTACT_OPTIONAL.iter())
+        .copied()
+        .collect();
+    merge(contact, payload, &fields);
+    Reply::ok(contact.clone())
+}
+
+/// Checks the email and phone of the contact, when creating it or when they are changed.
+fn validate_contact(validation: &mut Validation, payload: &Value, creating: bool) {
+    if (creating || payload.get("email").is_some()) && !text(payload, "email").contains('@') {
+        validation.error("email", "is an invalid email address");
+    }
+
+    let digits = text(payload, "phone")
+        .chars()
+        .filter(char::is_ascii_digit)
+        .count();
+    if (creating || payload.get("phone").is_some()) && digits < 7 {
+        validation.error("phone", "is probably not a phone number");
+    }
+}
+
+fn templates(state: &mut State, account_id: u64, rest: &[&str], request: &Request) -> Reply {
+    let payload = &request.body;
+
+    match (request.method.as_str(), rest) {
+        ("GET", []) => list(&state.templates, account_id, request),
+        ("POST", []) => {
+            let mut validation = Validation::default();
+            validation.required(payload, "name");
+            validation.required(payload, "sid");
+            let sid = text(payload, "sid");
+            if !sid.is_empty() && find(&state.templates, account_id, &sid, &["sid"]).is_some() {
+                validation.error("sid", "has already been taken");
+            }
+            if let Some(reply) = validation.into_reply() {
+                return reply;
+            }
+
+            let now = timestamp(SystemTime::now());
+            let template = json!({
+                "id": state.next_id(),
+                "account_id": account_id,
+                "name": text(payload, "name"),
+                "sid": sid,
+                "description": text(payload, "description"),
+                "created_at": now,
+                "updated_at": now,
+            });
+            state.templates.push(template.clone());
+            Reply::created(template)
+        }
+        (method, [template, records @ ..]) => {
+            let index = match find(&state.templates, account_id, template, &["id", "sid"]) {
+                Some(index) => index,
+                None => return Reply::not_found("Template", template),
+            };
+            let template_id = state.templates[index]["id"].clone();
+
+            match (method, records) {
+                ("GET", []) => Reply::ok(state.templates[index].clone()),
+                ("PATCH", []) => {
+                    let mut validation = Validation::default();
+                    for field in ["name", "sid"] {
+                        if payload.get(field).map_or(false, |value| value == "") {
+                            validation.error(field, "can't be blank");
+                        }
+                    }
+                    if let Some(reply) = validation.into_reply() {
+                        return reply;
+                    }
+
+                    let template = &mut state.templates[index];
+                    merge(template, payload, &["name", "sid", "description"]);
+                    Reply::ok(template.clone())
+                }
+                ("DELETE", []) => {
+                    state.templates.remove(index);
+                    state
+                        .template_records
+                        .retain(|record| record["template_id"] != template_id);
+                    Reply::no_content()
+                }
+                ("GET", ["records"]) => {
+                    let records: Vec<Value> = state
+                        .template_records
+                        .iter()
+                        .filter(|record| record["template_id"] == template_id)
+                        .cloned()
+                        .collect();
+                    list(&records, account_id, request)
+                }
+                ("POST", ["records"]) => {
+                    let mut validation = Validation::default();
+                    validation.record_type(payload);
+                    validation.required(payload, "content");
+                    if let Some(reply) = validation.into_reply() {
+                        return reply;
+                    }
+
+                    let now = timestamp(SystemTime::now());
+                    let record = json!({
+                        "id": state.next_id(),
+                        "account_id": account_id,
+                        "template_id": template_id,
+                        "name": text(payload, "name"),
+                        "content": text(payload, "content"),
+                        "ttl": payload["ttl"].as_u64().unwrap_or(DEFAULT_TTL),
+                        "priority": payload["priority"],
+                        "type": text(payload, "type"),
+                        "created_at": now,
+                        "updated_at": now,
+                    });
+                    state.template_records.push(record.clone());
+                    Reply::created(record)
+                }
+                (method, ["records", record]) => {
+                    let index = state
+                        .template_records
+                        .iter()
+                        .position(|r| r["template_id"] == template_id && is_id(&r["id"], record));
+                    let index = match index {
+                        Some(index) => index,
+                        None => return Reply::not_found("Record", record),
+                    };
+
+                    match method {
+                        "GET" => Reply::ok(state.template_records[index].clone()),
+                        "DELETE" => {
+                            state.template_records.remove(index);
+                            Reply::no_content()
+                        }
+                        _ => Reply::error(404, "Not found"),
+                    }
+                }
+                _ => Reply::error(404, "Not found"),
+            }
+        }
+        _ => Reply::error(404, "Not found"),
+    }
+}
+
+fn create_webhook(state: &mut State, account_id: u64, payload: &Value) -> Reply {
+    let url = text(payload, "url");
+    let mut validation = Validation::default();
+    if url.is_empty() {
+        validation.error("url", "can't be blank");
+    } else if !(url.starts_with("http://") || url.starts_with("https://")) {
+        validation.error("url", "is not a valid URL");
+    }
+    if let Some(reply) = validation.into_reply() {
+        return reply;
+    }
+
+    let webhook = json!({
+        "id": state.next_id(),
+        "account_id": account_id,
+        "url": url,
+    });
+    state.webhooks.push(webhook.clone());
+    Reply::created(webhook)
+}
+
+/// Lists the items of the account, paginated as asked in the query.
+fn list(items: &[Value], account_id: u64, request: &Request) -> Reply {
+    let items: Vec<&Value> = items
+        .iter()
+        .filter(|item| item["account_id"] == account_id)
+        .collect();
+    let parameter = |name: &str| {
+        request
+            .query
+            .iter()
+            .find(|(key, _)| key == name)
+            .and_then(|(_, value)| value.parse::<usize>().ok())
+            .filter(|value| *value > 0)
+    };
+    let page = parameter("page").unwrap_or(1);
+    let per_page = parameter("per_page").unwrap_or(DEFAULT_PER_PAGE);
+
+    let data: Vec<&Value> = items
+        .iter()
+        .skip((page - 1).saturating_mul(per_page))
+        .take(per_page)
+        .copied()
+        .collect();
+
+    Reply {
+        status: 200,
+        body: Some(json!({
+            "data": data,
+            "pagination": {
+                "current_page": page,
+                "per_page": per_page,
+                "total_entries": items.len(),
+                "total_pages": (items.len() + per_page - 1) / per_page,
+            },
+        })),
+    }
+}
+
+fn get(items: &[Value], account_id: u64, kind: &str, id: &str, keys: &[&str]) -> Reply {
+    match find(items, account_id, id, keys) {
+        Some(index) => Reply::ok(items[index].clone()),
+        None => Reply::not_found(kind, id),
+    }
+}
+
+fn delete(items: &mut Vec<Value>, account_id: u64, kind: &str, id: &str, keys: &[&str]) -> Reply {
+    match find(items, account_id, id, keys) {
+        Some(index) => {
+            items.remove(index);
+            Reply::no_content()
+        }
+        None => Reply::not_found(kind, id),
+    }
+}
+
+/// Finds the item of the account whose id (or name, sid...) is the one given.
+fn find(items: &[Value], account_id: u64, id: &str, keys: &[&str]) -> Option<usize> {
+    items.iter().position(|item| {
+        item["account_id"] == account_id && keys.iter().any(|key| is_id(&item[*key], id))
+    })
+}
+
+/// Whether the value (a number or a string) is the id given in the path.
+fn is_id(value: &Value, id: &str) -> bool {
+    match value {
+        Value::String(value) => value == id,
+        Value::Number(number) => number.as_u64() == id.parse().ok(),
+        _ => false,
+    }
+}
+
+/// Copies the given (non null) fields of the payload over the item, touching its `updated_at`.
+fn merge(item: &mut Value, payload: &Value, fields: &[&str]) {
+    for field in fields {
+        if let Some(value) = payload.get(*field).filter(|value| !value.is_null()) {
+            item[*field] = value.clone();
+        }
+    }
+    item["updated_at"] = Value::from(timestamp(SystemTime::now()));
+}
+
+fn text(payload: &Value, field: &str) -> String {
+    payload
+        .get(field)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+/// Formats the time the way the API does (i.e. `2016-01-19T20:50:26Z`).
+fn timestamp(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+
+    // Converts the days since the epoch into a civil date (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{status_text, timestamp};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn formats_timestamps_like_the_api() {
+        assert_eq!("1970-01-01T00:00:00Z", timestamp(UNIX_EPOCH));
+        assert_eq!(
+            "2016-01-19T20:50:26Z",
+            timestamp(UNIX_EPOCH + Duration::from_secs(1_453_236_626))
+        );
+        assert_eq!(
+            "2024-02-29T12:00:00Z",
+            timestamp(UNIX_EPOCH + Duration::from_secs(1_709_208_000))
+        );
+    }
+
+    #[test]
+    fn knows_the_reason_phrases() {
+        assert_eq!("Unprocessable Entity", status_text(422));
+        assert_eq!("Internal Server Error", status_text(500));
+        assert_eq!("", status_text(418));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::common::{read_fixture, setup_mock_for};
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::new_client;
+use dnsimple::errors::DNSimpleError;
+use mockito::{Matcher, Server};
+mod common;
+
+#[test]
+fn account_scoped_list_zones_test() {
+    let setup = setup_mock_for("/1010/zones", "listZones/success", "GET");
+    let client = setup.0;
+
+    let account = client.account(1010);
+    let zones = account.zones().list_zones(None).unwrap().data.unwrap();
+
+    assert_eq!(1010, account.id());
+    assert_eq!(2, zones.len());
+    assert_eq!(1010, zones[0].account_id);
+}
+
+#[test]
+fn account_scoped_get_zone_record_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/records/5",
+        "getZoneRecord/success",
+        "GET",
+    );
+    let client = setup.0;
+
+    let record = client
+        .account(1010)
+        .zones()
+        .get_zone_record("example.com", 5)
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(5, record.id);
+    assert_eq!("example.com", record.zone_id);
+}
+
+#[test]
+fn account_scoped_list_webhooks_iter_test() {
+    let (status, body) = read_fixture("listWebhooks/success");
+    let mut server = Server::new();
+    server
+        .mock("GET", "/v2/1010/webhooks")
+        .match_query(Matcher::Any)
+        .with_status(status)
+        .with_body(body)
+        .create();
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
+
+    let webhooks = client
+        .account(1010)
+        .webhooks()
+        .list_webhooks_iter(None)
+        .collect_all()
+        .unwrap();
+
+    assert_eq!(2, webhooks.len());
+}
+
+#[test]
+fn default_account_test() {
+    let mut setup = setup_mock_for("/whoami", "whoami/success-account", "GET");
+    let (status, body) = read_fixture("listDomains/success");
+    setup
+        .1
+        .mock("GET", "/v2/1/domains")
+        .with_status(status)
+        .with_body(body)
+        .create();
+    let client = setup.0;
+
+    let account = client.default_account().unwrap();
+    let domains = account.domains().list_domains(None).unwrap().data.unwrap();
+
+    assert_eq!(1, account.id());
+    assert_eq!(2, domains.len());
+}
+
+#[test]
+fn default_account_with_a_user_token_test() {
+    let setup = setup_mock_for("/whoami", "whoami/success-user", "GET");
+    let client = setup.0;
+
+    let error = client.default_account().err().unwrap();
+
+    assert_matches!(error, DNSimpleError::Configuration(_));
+}
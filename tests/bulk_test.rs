@@ -0,0 +1,142 @@
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::zones_records::ZoneRecordPayload;
+use dnsimple::dnsimple::{Client, RetryPolicy};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Server, ServerGuard};
+use std::time::Duration;
+
+fn client_for(server: &ServerGuard) -> Client {
+    Client::builder(String::from("some-token"))
+        .base_url(&server.url())
+        .build()
+        .unwrap()
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(10),
+        retry_non_idempotent: false,
+    }
+}
+
+fn payload(name: &str) -> ZoneRecordPayload {
+    ZoneRecordPayload {
+        name: String::from(name),
+        record_type: String::from("A"),
+        content: String::from("192.0.2.1"),
+        ttl: None,
+        priority: None,
+        regions: None,
+    }
+}
+
+#[test]
+fn reports_every_operation_in_order_test() {
+    let mut server = Server::new();
+    let deleted = server
+        .mock(
+            "DELETE",
+            mockito::Matcher::Regex(String::from("^/v2/1010/zones/example.com/records/[12]$")),
+        )
+        .with_status(204)
+        .expect(2)
+        .create();
+    let missing = server
+        .mock("DELETE", "/v2/1010/zones/example.com/records/3")
+        .with_status(404)
+        .with_body("{\"message\":\"Record `3` not found\"}")
+        .expect(1)
+        .create();
+    let client = client_for(&server);
+    let ids: Vec<u64> = vec![1, 2, 3];
+
+    let report = client.bulk().workers(2).run(ids.iter().map(|id| {
+        move |client: &Client| client.zones().delete_zone_record(1010, "example.com", *id)
+    }));
+
+    deleted.assert();
+    missing.assert();
+    assert!(!report.is_success());
+    assert_eq!(
+        vec![0, 1, 2],
+        report.outcomes.iter().map(|o| o.index).collect::<Vec<_>>()
+    );
+    assert_eq!(2, report.succeeded().count());
+
+    let failed: Vec<_> = report.failed().collect();
+    assert_eq!(1, failed.len());
+    assert_eq!(2, failed[0].index);
+    assert_eq!(1, failed[0].attempts);
+    assert_matches!(failed[0].result, Err(DNSimpleError::NotFound { ref message, .. }) if message == "Record `3` not found");
+}
+
+#[test]
+fn runs_throttled_operations_again_test() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/v2/1010/zones/example.com/records")
+        .with_status(429)
+        .with_header("X-RateLimit-Limit", "2400")
+        .with_header("X-RateLimit-Remaining", "0")
+        .with_header("X-RateLimit-Reset", "0")
+        .expect(3)
+        .create();
+    let client = client_for(&server);
+
+    let report = client
+        .bulk()
+        .retry_policy(policy())
+        .run(vec![|client: &Client| {
+            client
+                .zones()
+                .create_zone_record(1010, "example.com", payload("www"))
+        }]);
+
+    mock.assert();
+    assert_eq!(3, report.outcomes[0].attempts);
+    assert_matches!(
+        report.outcomes[0].result,
+        Err(DNSimpleError::TooManyRequests { .. })
+    );
+}
+
+#[test]
+fn does_not_run_non_idempotent_operations_again_on_server_errors_test() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/v2/1010/zones/example.com/records")
+        .with_status(503)
+        .expect(1)
+        .create();
+    let client = client_for(&server);
+
+    let report = client
+        .bulk()
+        .retry_policy(policy())
+        .run(vec![|client: &Client| {
+            client
+                .zones()
+                .create_zone_record(1010, "example.com", payload("www"))
+        }]);
+
+    mock.assert();
+    assert_eq!(1, report.outcomes[0].attempts);
+}
+
+fn whoami(client: &Client) -> Result<(), DNSimpleError> {
+    client.identity().whoami().map(|_| ())
+}
+
+#[test]
+fn runs_nothing_without_operations_test() {
+    let server = Server::new();
+    let client = client_for(&server);
+    let operations = vec![whoami; 0];
+
+    let report = client.bulk().run(operations);
+
+    assert!(report.outcomes.is_empty());
+    assert!(report.is_success());
+}
@@ -0,0 +1,220 @@
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::cassette::{Interaction, RecordedRequest, RecordedResponse};
+use dnsimple::dnsimple::oauth::OAuthTokenPayload;
+use dnsimple::dnsimple::zones_records::ZoneRecordListOptions;
+use dnsimple::dnsimple::zones_records::ZoneRecordPayload;
+use dnsimple::dnsimple::{Client, Paginate, RecordingTransport, ReplayTransport, UreqTransport};
+use dnsimple::errors::DNSimpleError;
+use mockito::Server;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn cassette_path(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("dnsimple-{}-{}.json", name, std::process::id()))
+}
+
+fn record_payload() -> ZoneRecordPayload {
+    ZoneRecordPayload {
+        name: String::from("www"),
+        record_type: String::from("A"),
+        content: String::from("127.0.0.1"),
+        ttl: Some(3600),
+        priority: None,
+        regions: None,
+    }
+}
+
+fn replaying_client(path: &PathBuf) -> Client {
+    Client::builder(String::from("another-token"))
+        .base_url("https://api.example.com")
+        .transport(ReplayTransport::from_file(path).unwrap())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn records_the_interactions_and_replays_them_offline() {
+    let path = cassette_path("replay");
+    let mut server = Server::new();
+    server
+        .mock("GET", "/v2/whoami")
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3991")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body(
+            fs::read_to_string("./tests/fixtures/v2/api/whoami/success.http")
+                .unwrap()
+                .lines()
+                .last()
+                .unwrap(),
+        )
+        .create();
+    server
+        .mock("POST", "/v2/1010/zones/example.com/records")
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3990")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_status(201)
+        .with_body(
+            fs::read_to_string("./tests/fixtures/v2/api/createZoneRecord/created.http")
+                .unwrap()
+                .lines()
+                .last()
+                .unwrap(),
+        )
+        .create();
+    let recording = Client::builder(String::from("secret-token"))
+        .base_url(&server.url())
+        .transport(RecordingTransport::new(UreqTransport::default(), &path))
+        .build()
+        .unwrap();
+
+    recording.identity().whoami().unwrap();
+    recording
+        .zones()
+        .create_zone_record(1010, "example.com", record_payload())
+        .unwrap();
+    drop(server);
+
+    let cassette = fs::read_to_string(&path).unwrap();
+    assert!(!cassette.contains("secret-token"));
+
+    let client = replaying_client(&path);
+    let whoami = client.identity().whoami().unwrap();
+    let record = client
+        .zones()
+        .create_zone_record(1010, "example.com", record_payload())
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(1, whoami.data.unwrap().account.unwrap().id);
+    assert_eq!(3991, whoami.rate_limit.unwrap().remaining);
+    assert_eq!("www", record.name);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn filters_the_secrets_of_the_oauth_exchange() {
+    let path = cassette_path("oauth");
+    let mut server = Server::new();
+    server
+        .mock("POST", "/v2/oauth/access_token")
+        .with_body(
+            fs::read_to_string("./tests/fixtures/v2/api/oauthAccessToken/success.http")
+                .unwrap()
+                .lines()
+                .last()
+                .unwrap(),
+        )
+        .create();
+    let payload = || OAuthTokenPayload {
+        client_id: String::from("client-id"),
+        client_secret: String::from("client-secret"),
+        code: String::from("authorization-code"),
+        redirect_uri: String::from("/redirect_uri"),
+        state: String::from("state"),
+    };
+    let recording = Client::builder(String::from("secret-token"))
+        .base_url(&server.url())
+        .transport(RecordingTransport::new(UreqTransport::default(), &path))
+        .build()
+        .unwrap();
+
+    recording
+        .oauth()
+        .exchange_authorization_for_token(payload())
+        .unwrap();
+    drop(server);
+
+    let cassette = fs::read_to_string(&path).unwrap();
+    assert!(!cassette.contains("client-secret"));
+    assert!(!cassette.contains("authorization-code"));
+    assert!(!cassette.contains("zKQ7OLqF5N1gylcJweA9WodA000BUNJD"));
+    assert!(cassette.contains("client-id"));
+
+    let token = replaying_client(&path)
+        .oauth()
+        .exchange_authorization_for_token(payload())
+        .unwrap();
+
+    assert_eq!("[FILTERED]", token.access_token);
+    assert_eq!(1, token.account_id);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn fails_for_requests_not_in_the_cassette() {
+    let path = cassette_path("unmatched");
+    fs::write(
+        &path,
+        r#"{"interactions":[{"request":{"method":"GET","path":"/v2/whoami","query":[],"body":null},"response":{"status":200,"status_text":"OK","headers":[],"body":"{\"data\":{\"account\":null,\"user\":null}}"}}]}"#,
+    )
+    .unwrap();
+    let client = replaying_client(&path);
+
+    client.identity().whoami().unwrap();
+    client.identity().whoami().unwrap();
+    let error = client
+        .zones()
+        .create_zone_record(1010, "example.com", record_payload())
+        .unwrap_err();
+
+    assert_matches!(error, DNSimpleError::Transport(message, _) if message == "no interaction recorded for POST /v2/1010/zones/example.com/records");
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn replays_the_query_parameters_in_any_order() {
+    let interaction = Interaction {
+        request: RecordedRequest {
+            method: String::from("GET"),
+            path: String::from("/v2/1010/zones/example.com/records"),
+            query: vec![
+                (String::from("type"), String::from("A")),
+                (String::from("per_page"), String::from("10")),
+                (String::from("name_like"), String::from("www")),
+                (String::from("page"), String::from("1")),
+            ],
+            body: None,
+        },
+        response: RecordedResponse {
+            status: 200,
+            status_text: String::from("OK"),
+            headers: vec![],
+            body: String::from("{\"data\":[]}"),
+        },
+    };
+    let client = Client::builder(String::from("some-token"))
+        .transport(ReplayTransport::new(vec![interaction]))
+        .build()
+        .unwrap();
+
+    for _ in 0..50 {
+        let options = ZoneRecordListOptions {
+            name_like: Some(String::from("www")),
+            record_type: Some(String::from("A")),
+            paginate: Some(Paginate {
+                per_page: 10,
+                page: 1,
+            }),
+            ..Default::default()
+        };
+
+        client
+            .zones()
+            .list_zone_records(1010, "example.com", Some(options.into()))
+            .unwrap();
+    }
+}
+
+#[test]
+fn fails_to_load_a_missing_cassette() {
+    let result = ReplayTransport::from_file(cassette_path("missing"));
+
+    assert_matches!(result, Err(DNSimpleError::Configuration(_)));
+}
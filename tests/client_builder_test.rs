@@ -0,0 +1,53 @@
+use dnsimple::dnsimple::ClientBuilder;
+use mockito::Server;
+use std::time::Duration;
+
+#[test]
+fn sends_the_configured_user_agent() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/v2/whoami")
+        .match_header(
+            "User-Agent",
+            format!("dnsimple-rust/{} my-app/1.0", env!("CARGO_PKG_VERSION")).as_str(),
+        )
+        .with_header("X-RateLimit-Limit", "2")
+        .with_header("X-RateLimit-Remaining", "2")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body(r#"{"data":{"user":null,"account":null}}"#)
+        .create();
+
+    let client = ClientBuilder::new(String::from("some-token"))
+        .base_url(&server.url())
+        .user_agent("my-app/1.0")
+        .build()
+        .unwrap();
+
+    client.identity().whoami().unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn uses_the_injected_agent() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/v2/whoami")
+        .with_header("X-RateLimit-Limit", "2")
+        .with_header("X-RateLimit-Remaining", "2")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body(r#"{"data":{"user":null,"account":null}}"#)
+        .create();
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(5))
+        .build();
+
+    let client = ClientBuilder::new(String::from("some-token"))
+        .base_url(&server.url())
+        .agent(agent)
+        .build()
+        .unwrap();
+
+    assert!(client.identity().whoami().is_ok());
+    mock.assert();
+}
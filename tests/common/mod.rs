@@ -1,6 +1,65 @@
+#[cfg(feature = "async")]
+use dnsimple::dnsimple::{
+    accounts::Accounts, certificates::Certificates, contacts::Contacts, domains::Domains,
+    identity::Identity, new_async_client, oauth::OAuth, registrar::Registrar, services::Services,
+    templates::Templates, tlds::Tlds, vanity_name_servers::VanityNameServers, webhooks::Webhooks,
+    zones::Zones, ApiClient, AsyncClient, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint,
+    RequestOptions,
+};
 use dnsimple::dnsimple::{new_client, Client};
+#[cfg(feature = "async")]
+use dnsimple::errors::DNSimpleError;
 use mockito::{Server, ServerGuard};
+#[cfg(feature = "async")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "async")]
+use serde::Serialize;
 use std::fs;
+#[cfg(feature = "async")]
+use std::sync::Arc;
+#[cfg(feature = "async")]
+use tokio::runtime::Runtime;
+
+/// Runs each test against the `Client` and, with the `async` feature, the `AsyncClient`
+///
+/// Every test becomes a module with a `client` test and an `async_client` test, where
+/// `setup_mock_for` gives a `BlockingAsyncClient` instead: the same body calls the same
+/// services, the async one through the futures of the `AsyncClient`.
+///
+/// ```ignore
+/// client_tests! {
+///     fn whoami_test() {
+///         let setup = setup_mock_for("/whoami", "whoami/success-account", "GET");
+///         let client = setup.0;
+///
+///         assert!(client.identity().whoami().unwrap().data.is_some());
+///     }
+/// }
+/// ```
+#[allow(unused_macros)]
+macro_rules! client_tests {
+    ($($(#[$attr:meta])* fn $name:ident() $body:block)*) => {
+        $(
+            $(#[$attr])*
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn client() $body
+
+                #[cfg(feature = "async")]
+                #[test]
+                fn async_client() {
+                    use crate::common::setup_blocking_async_mock_for as setup_mock_for;
+
+                    $body
+                }
+            }
+        )*
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use client_tests;
 
 /// Creates a mockserver and a client (changing the url of the client
 /// to that of the mockserver to capture the requests).
@@ -13,28 +72,268 @@ use std::fs;
 /// `path`: the path in the server (i.e. `/whoami`)
 /// `method`: the HTTP method we are going to use (GET, POST, DELETE, ...)
 ///
+#[allow(dead_code)]
 pub fn setup_mock_for(path: &str, fixture: &str, method: &str) -> (Client, ServerGuard) {
-    let path = format!("/v2{}", path);
-    let fixture = format!("./tests/fixtures/v2/api/{}.http", fixture);
+    let server = mock_server_for(path, fixture, method);
 
-    let content =
-        fs::read_to_string(fixture.as_str()).expect("Something went wrong: Couldn't read the file");
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
+    (client, server)
+}
 
-    let lines = content.lines();
-    let status = &content[9..12];
-    let body = lines.last();
+/// Same as `setup_mock_for` but for the `AsyncClient`, wrapped in a `BlockingAsyncClient`
+/// so it can be called from a blocking test.
+#[cfg(feature = "async")]
+#[allow(dead_code)]
+pub fn setup_blocking_async_mock_for(
+    path: &str,
+    fixture: &str,
+    method: &str,
+) -> (BlockingAsyncClient, ServerGuard) {
+    let server = mock_server_for(path, fixture, method);
+
+    let mut client = new_async_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
+    (BlockingAsyncClient::new(client), server)
+}
+
+/// Creates a mockserver answering the request with the fixture.
+#[allow(dead_code)]
+fn mock_server_for(path: &str, fixture: &str, method: &str) -> ServerGuard {
+    let path = format!("/v2{}", path);
+    let (status, body) = read_fixture(fixture);
 
     let mut server = Server::new();
     server
         .mock(method, path.as_str())
         .with_header("X-RateLimit-Limit", "2")
         .with_header("X-RateLimit-Remaining", "2")
-        .with_header("X-RateLimit-Reset", "never")
-        .with_status(status.parse().unwrap())
-        .with_body(body.unwrap())
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_status(status)
+        .with_body(body)
         .create();
+    server
+}
 
-    let mut client = new_client(true, String::from("some-token"));
+/// Same as `setup_mock_for` but for the `AsyncClient`, to be called from
+/// within an async test.
+#[cfg(feature = "async")]
+#[allow(dead_code)]
+pub async fn setup_async_mock_for(
+    path: &str,
+    fixture: &str,
+    method: &str,
+) -> (AsyncClient, ServerGuard) {
+    let path = format!("/v2{}", path);
+    let (status, body) = read_fixture(fixture);
+
+    let mut server = Server::new_async().await;
+    server
+        .mock(method, path.as_str())
+        .with_header("X-RateLimit-Limit", "2")
+        .with_header("X-RateLimit-Remaining", "2")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_status(status)
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let mut client = new_async_client(true, String::from("some-token"));
     client.set_base_url(&server.url());
     (client, server)
 }
+
+/// Reads the status code and the body out of a fixture in the `api` directory.
+#[allow(dead_code)]
+pub fn read_fixture(fixture: &str) -> (usize, String) {
+    let fixture = format!("./tests/fixtures/v2/api/{}.http", fixture);
+
+    let content =
+        fs::read_to_string(fixture.as_str()).expect("Something went wrong: Couldn't read the file");
+
+    let status = content[9..12].parse().unwrap();
+    let body = content.lines().last().unwrap().to_string();
+
+    (status, body)
+}
+
+/// An `AsyncClient` whose calls are run to completion on a runtime of its own
+///
+/// It lets the blocking tests call the services of the `AsyncClient` with the exact same
+/// code as for the `Client` (see `client_tests`).
+#[cfg(feature = "async")]
+#[derive(Clone)]
+#[allow(dead_code)]
+pub struct BlockingAsyncClient {
+    client: AsyncClient,
+    runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "async")]
+#[allow(dead_code)]
+impl BlockingAsyncClient {
+    pub fn new(client: AsyncClient) -> BlockingAsyncClient {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        BlockingAsyncClient {
+            client,
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    pub fn accounts(&self) -> Accounts<BlockingAsyncClient> {
+        Accounts {
+            client: self.clone(),
+        }
+    }
+
+    pub fn certificates(&self) -> Certificates<BlockingAsyncClient> {
+        Certificates {
+            client: self.clone(),
+        }
+    }
+
+    pub fn contacts(&self) -> Contacts<BlockingAsyncClient> {
+        Contacts {
+            client: self.clone(),
+        }
+    }
+
+    pub fn domains(&self) -> Domains<BlockingAsyncClient> {
+        Domains {
+            client: self.clone(),
+        }
+    }
+
+    pub fn identity(&self) -> Identity<BlockingAsyncClient> {
+        Identity {
+            client: self.clone(),
+        }
+    }
+
+    pub fn oauth(&self) -> OAuth<BlockingAsyncClient> {
+        OAuth {
+            client: self.clone(),
+        }
+    }
+
+    pub fn registrar(&self) -> Registrar<BlockingAsyncClient> {
+        Registrar {
+            client: self.clone(),
+        }
+    }
+
+    pub fn services(&self) -> Services<BlockingAsyncClient> {
+        Services {
+            client: self.clone(),
+        }
+    }
+
+    pub fn templates(&self) -> Templates<BlockingAsyncClient> {
+        Templates {
+            client: self.clone(),
+        }
+    }
+
+    pub fn tlds(&self) -> Tlds<BlockingAsyncClient> {
+        Tlds {
+            client: self.clone(),
+        }
+    }
+
+    pub fn vanity_name_servers(&self) -> VanityNameServers<BlockingAsyncClient> {
+        VanityNameServers {
+            client: self.clone(),
+        }
+    }
+
+    pub fn webhooks(&self) -> Webhooks<BlockingAsyncClient> {
+        Webhooks {
+            client: self.clone(),
+        }
+    }
+
+    pub fn zones(&self) -> Zones<BlockingAsyncClient> {
+        Zones {
+            client: self.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl ApiClient for BlockingAsyncClient {
+    type Result<'a, T: 'a> = Result<T, DNSimpleError>;
+
+    fn get<E: Endpoint>(
+        &self,
+        path: &str,
+        options: Option<RequestOptions>,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.runtime
+            .block_on(ApiClient::get::<E>(&self.client, path, options))
+    }
+
+    fn post<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.runtime
+            .block_on(ApiClient::post::<E>(&self.client, path, data))
+    }
+
+    fn empty_post(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        self.runtime
+            .block_on(ApiClient::empty_post(&self.client, path))
+    }
+
+    fn put<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.runtime
+            .block_on(ApiClient::put::<E>(&self.client, path, data))
+    }
+
+    fn empty_put(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        self.runtime
+            .block_on(ApiClient::empty_put(&self.client, path))
+    }
+
+    fn patch<E: Endpoint>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.runtime
+            .block_on(ApiClient::patch::<E>(&self.client, path, data))
+    }
+
+    fn delete(&self, path: &str) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        self.runtime.block_on(ApiClient::delete(&self.client, path))
+    }
+
+    fn delete_with_response<E: Endpoint>(
+        &self,
+        path: &str,
+    ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
+        self.runtime
+            .block_on(ApiClient::delete_with_response::<E>(&self.client, path))
+    }
+
+    fn unauthenticated_post<T: DeserializeOwned + 'static>(
+        &self,
+        path: &str,
+        data: impl Serialize,
+    ) -> Result<T, DNSimpleError> {
+        self.runtime
+            .block_on(ApiClient::unauthenticated_post(&self.client, path, data))
+    }
+
+    fn fail<T: 'static>(&self, error: DNSimpleError) -> Result<T, DNSimpleError> {
+        self.runtime.block_on(ApiClient::fail(&self.client, error))
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "config")]
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::{AccountId, Client};
+use dnsimple::errors::DNSimpleError;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A config file in the temporary directory, removed once the test is done with it.
+struct ConfigFile(PathBuf);
+
+impl AsRef<Path> for ConfigFile {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for ConfigFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn config_file(name: &str, content: &str) -> ConfigFile {
+    let path = std::env::temp_dir().join(format!("dnsimple-{}-{}.toml", name, std::process::id()));
+    fs::write(&path, content).unwrap();
+    ConfigFile(path)
+}
+
+#[test]
+fn reads_a_profile_test() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v2/1010/zones")
+        .match_header("Authorization", "Bearer sandbox-token")
+        .with_body("{\"data\":[],\"pagination\":{\"current_page\":1,\"per_page\":30,\"total_entries\":0,\"total_pages\":1}}")
+        .create();
+    let path = config_file(
+        "profile",
+        &format!(
+            "[profiles.default]\ntoken = \"production-token\"\n\n[profiles.sandbox]\ntoken = \"sandbox-token\"\naccount_id = 1010\nbase_url = \"{}\"\n",
+            server.url()
+        ),
+    );
+
+    let client = Client::from_config_profile(&path, "sandbox").unwrap();
+    let zones = client
+        .default_account()
+        .unwrap()
+        .zones()
+        .list_zones(None)
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(Some(AccountId(1010)), client.account_id());
+    assert_eq!(0, zones.data.unwrap().len());
+}
+
+#[test]
+fn names_the_missing_token_test() {
+    let path = config_file("missing-token", "[profiles.default]\nsandbox = true\n");
+
+    let error = Client::from_config_profile(&path, "default").err().unwrap();
+
+    assert_matches!(error, DNSimpleError::Configuration(message) if message.starts_with("missing setting `token` in `[profiles.default]`"));
+}
+
+#[test]
+fn fails_with_a_missing_profile_test() {
+    let path = config_file("missing-profile", "[profiles.default]\ntoken = \"token\"\n");
+
+    let error = Client::from_config_profile(&path, "staging").err().unwrap();
+
+    assert_matches!(error, DNSimpleError::Configuration(message) if message.contains("[profiles.staging]"));
+}
+
+#[test]
+fn fails_with_a_missing_file_test() {
+    let error = Client::from_config_profile("/nonexistent/dnsimple.toml", "default")
+        .err()
+        .unwrap();
+
+    assert_matches!(error, DNSimpleError::Configuration(_));
+}
@@ -0,0 +1,127 @@
+use dnsimple::dnsimple::zones_records::ZoneRecordPayload;
+use dnsimple::dnsimple::{new_client, Client, DryRun, Method, PlannedOperation};
+use mockito::{Matcher, Server};
+use serde_json::json;
+
+#[test]
+fn records_the_mutating_calls_and_sends_the_others() {
+    let mut server = Server::new();
+    let whoami = server
+        .mock("GET", "/v2/whoami")
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3991")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body("{\"data\":{\"account\":null,\"user\":null}}")
+        .create();
+    let mutations = ["POST", "PUT", "PATCH", "DELETE"]
+        .map(|method| server.mock(method, Matcher::Any).expect(0).create());
+    let plan = DryRun::new();
+    let client = Client::builder(String::from("some-token"))
+        .base_url(&server.url())
+        .dry_run(plan.clone())
+        .build()
+        .unwrap();
+    let payload = ZoneRecordPayload {
+        name: String::from("www"),
+        record_type: String::from("A"),
+        content: String::from("127.0.0.1"),
+        ttl: Some(3600),
+        priority: None,
+        regions: None,
+    };
+
+    client.identity().whoami().unwrap();
+    let created = client
+        .zones()
+        .create_zone_record(1010, "example.com", payload)
+        .unwrap();
+    let deleted = client
+        .zones()
+        .delete_zone_record(1010, "example.com", 5)
+        .unwrap();
+    client
+        .templates()
+        .apply_template(1010, String::from("example.com"), String::from("alpha"))
+        .unwrap();
+
+    assert_eq!(202, created.status);
+    assert!(created.data.is_none());
+    assert_eq!(202, deleted.status);
+    assert_eq!(
+        vec![
+            PlannedOperation {
+                method: String::from("POST"),
+                path: String::from("/1010/zones/example.com/records"),
+                query: Vec::new(),
+                payload: Some(json!({
+                    "name": "www",
+                    "type": "A",
+                    "content": "127.0.0.1",
+                    "ttl": 3600,
+                    "priority": null,
+                    "regions": null
+                })),
+            },
+            PlannedOperation {
+                method: String::from("DELETE"),
+                path: String::from("/1010/zones/example.com/records/5"),
+                query: Vec::new(),
+                payload: None,
+            },
+            PlannedOperation {
+                method: String::from("POST"),
+                path: String::from("/1010/domains/example.com/templates/alpha"),
+                query: Vec::new(),
+                payload: None,
+            },
+        ],
+        plan.operations()
+    );
+    whoami.assert();
+    for mock in mutations {
+        mock.assert();
+    }
+}
+
+#[test]
+fn can_be_enabled_on_an_existing_client() {
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url("http://127.0.0.1:1");
+    let plan = DryRun::new();
+    client.set_dry_run(plan.clone());
+
+    client
+        .registrar()
+        .enable_domain_auto_renewal(1010, String::from("example.com"))
+        .unwrap();
+
+    assert_eq!(
+        "PUT /1010/registrar/domains/example.com/auto_renewal",
+        plan.operations()[0].to_string()
+    );
+}
+
+#[test]
+fn records_the_query_parameters() {
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url("http://127.0.0.1:1");
+    let plan = DryRun::new();
+    client.set_dry_run(plan.clone());
+
+    client
+        .request::<serde_json::Value>(Method::Post, "/1010/zones/example.com/snapshots")
+        .query("notify", true)
+        .json(json!({ "label": "before-migration" }))
+        .send()
+        .unwrap();
+
+    let operation = &plan.operations()[0];
+    assert_eq!(
+        vec![(String::from("notify"), String::from("true"))],
+        operation.query
+    );
+    assert_eq!(
+        "POST /1010/zones/example.com/snapshots?notify=true {\"label\":\"before-migration\"}",
+        operation.to_string()
+    );
+}
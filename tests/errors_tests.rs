@@ -1,64 +1,228 @@
-use crate::common::setup_mock_for;
+use crate::common::{client_tests, setup_mock_for};
 use assert_matches::assert_matches;
-use dnsimple::errors::DNSimpleError;
+use dnsimple::dnsimple::new_client;
+use dnsimple::errors::{DNSimpleError, ErrorContext, TransportErrorKind};
+use mockito::Server;
 use serde_json::json;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 mod common;
 
-#[test]
-fn validation_error() {
-    let setup = setup_mock_for("/whoami", "validation-error", "GET");
-    let client = setup.0;
-
-    let response = client.identity().whoami();
-    let error = response.unwrap_err();
-
-    assert_eq!("Validation failed", error.to_string());
-    assert_matches!(error, DNSimpleError::BadRequest{ message, attribute_errors } => {
-      assert_eq!("Validation failed", message);
-      assert_eq!(json!({"address1":["can't be blank"],"city":["can't be blank"],"country":["can't be blank"],"email":["can't be blank","is an invalid email address"],"first_name":["can't be blank"],"last_name":["can't be blank"],"phone":["can't be blank","is probably not a phone number"],"postal_code":["can't be blank"],"state_province":["can't be blank"]}), attribute_errors.unwrap());
-    })
+client_tests! {
+    fn validation_error() {
+        let setup = setup_mock_for("/whoami", "validation-error", "GET");
+        let client = setup.0;
+
+        let response = client.identity().whoami();
+        let error = response.unwrap_err();
+
+        assert_eq!("Validation failed", error.to_string());
+        assert_matches!(error, DNSimpleError::BadRequest{ message, attribute_errors, .. } => {
+          assert_eq!("Validation failed", message);
+          assert_eq!(json!({"address1":["can't be blank"],"city":["can't be blank"],"country":["can't be blank"],"email":["can't be blank","is an invalid email address"],"first_name":["can't be blank"],"last_name":["can't be blank"],"phone":["can't be blank","is probably not a phone number"],"postal_code":["can't be blank"],"state_province":["can't be blank"]}), attribute_errors.unwrap());
+        })
+    }
+
+    fn typed_validation_errors() {
+        let setup = setup_mock_for("/whoami", "validation-error", "GET");
+        let client = setup.0;
+
+        let error = client.identity().whoami().unwrap_err();
+        let errors = error.validation_errors().unwrap();
+
+        assert_eq!(9, errors.len());
+        assert_eq!(
+            ["can't be blank", "is an invalid email address"],
+            errors.field("email")
+        );
+        assert!(errors.field("label").is_empty());
+        assert_eq!(
+            Some(("address1", &[String::from("can't be blank")][..])),
+            errors.iter().next()
+        );
+    }
 }
 
 #[test]
-fn not_found() {
-    let setup = setup_mock_for("/whoami", "notfound-certificate", "GET");
-    let client = setup.0;
+fn no_validation_errors_without_errors_object() {
+    let error = error_for(400, "{\"message\":\"Invalid request\"}");
+
+    assert_matches!(error, DNSimpleError::BadRequest { .. });
+    assert_eq!(None, error.validation_errors());
+    assert_eq!(
+        None,
+        error_for(404, "{\"message\":\"Record `5` not found\"}").validation_errors()
+    );
+}
+
+client_tests! {
+    fn not_found() {
+        let setup = setup_mock_for("/whoami", "notfound-certificate", "GET");
+        let client = setup.0;
+
+        let response = client.identity().whoami();
+        let error = response.unwrap_err();
+
+        assert_eq!("Certificate `0` not found", error.to_string());
+    }
+
+    fn method_not_allowed() {
+        let setup = setup_mock_for("/whoami", "method-not-allowed", "GET");
+        let client = setup.0;
+
+        let response = client.identity().whoami();
+        let error = response.unwrap_err();
 
-    let response = client.identity().whoami();
-    let error = response.unwrap_err();
+        assert_eq!("Method not Allowed", error.to_string());
+    }
 
-    assert_eq!("Certificate `0` not found", error.to_string());
+    fn bad_gateway() {
+        let setup = setup_mock_for("/whoami", "badgateway", "GET");
+        let client = setup.0;
+
+        let response = client.identity().whoami();
+        let error = response.unwrap_err();
+
+        assert_eq!("Bad Gateway", error.to_string());
+    }
+
+    fn unexpected_status() {
+        let setup = setup_mock_for("/other", "badgateway", "GET");
+        let client = setup.0;
+
+        let response = client.identity().whoami();
+        let error = response.unwrap_err();
+
+        assert_eq!(
+            "Unexpected HTTP status 501(Not Implemented)",
+            error.to_string()
+        );
+        assert_matches!(error, DNSimpleError::UnexpectedStatus { status_text, context } => {
+            assert_eq!("Not Implemented", status_text);
+            assert_eq!(501, context.status);
+        });
+    }
 }
 
-#[test]
-fn method_not_allowed() {
-    let setup = setup_mock_for("/whoami", "method-not-allowed", "GET");
-    let client = setup.0;
+fn error_for(status: usize, body: &str) -> DNSimpleError {
+    let mut server = Server::new();
+    server
+        .mock("DELETE", "/v2/1010/zones/example.com/records/5")
+        .with_status(status)
+        .with_header("X-Request-Id", "91dcf81b-5df4-4d45-b37e-446f0c422a27")
+        .with_body(body)
+        .create();
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
 
-    let response = client.identity().whoami();
-    let error = response.unwrap_err();
+    client
+        .zones()
+        .delete_zone_record(1010, "example.com", 5)
+        .unwrap_err()
+}
+
+#[test]
+fn carries_the_context_of_the_request() {
+    let error = error_for(404, "{\"message\":\"Record `5` not found\"}");
 
-    assert_eq!("Method not Allowed", error.to_string());
+    assert_eq!(
+        Some(&ErrorContext {
+            status: 404,
+            request_id: Some(String::from("91dcf81b-5df4-4d45-b37e-446f0c422a27")),
+            method: String::from("DELETE"),
+            path: String::from("/v2/1010/zones/example.com/records/5"),
+            body: String::from("{\"message\":\"Record `5` not found\"}"),
+            rate_limit: None,
+        }),
+        error.context()
+    );
+    assert_eq!(None, DNSimpleError::Configuration(String::new()).context());
 }
 
 #[test]
-fn bad_gateway() {
-    let setup = setup_mock_for("/whoami", "badgateway", "GET");
-    let client = setup.0;
+fn maps_the_other_statuses() {
+    assert_matches!(
+        error_for(403, "{\"message\":\"Permission denied\"}"),
+        DNSimpleError::Forbidden { message, .. } if message == "Permission denied"
+    );
+    assert_matches!(
+        error_for(409, "{\"message\":\"The record already exists\"}"),
+        DNSimpleError::Conflict { message, .. } if message == "The record already exists"
+    );
+    assert_matches!(
+        error_for(422, "{\"message\":\"Invalid\",\"errors\":{\"ttl\":[\"is too low\"]}}"),
+        DNSimpleError::UnprocessableEntity { message, attribute_errors, .. } => {
+            assert_eq!("Invalid", message);
+            assert_eq!(Some(json!({"ttl": ["is too low"]})), attribute_errors);
+        }
+    );
+    assert_matches!(
+        error_for(500, "<html>Oops</html>"),
+        DNSimpleError::InternalServerError { context } => {
+            assert_eq!("<html>Oops</html>", context.body);
+        }
+    );
+}
 
-    let response = client.identity().whoami();
-    let error = response.unwrap_err();
+#[test]
+fn classifies_the_errors() {
+    let not_found = error_for(404, "{\"message\":\"Record `5` not found\"}");
+    let unavailable = error_for(503, "");
+    let transport = DNSimpleError::Transport(
+        String::from("connection refused"),
+        TransportErrorKind::ConnectionFailed,
+    );
+    let dns = DNSimpleError::Transport(
+        String::from("failed to lookup address information"),
+        TransportErrorKind::Dns,
+    );
 
-    assert_eq!("Bad Gateway", error.to_string());
+    assert!(!not_found.is_retryable());
+    assert!(not_found.is_client_error());
+    assert!(unavailable.is_retryable());
+    assert!(!unavailable.is_client_error());
+    assert!(transport.is_retryable());
+    assert!(!transport.is_client_error());
+    assert!(dns.is_retryable());
+    assert_eq!(
+        "Transport Error - connection refused(Connection Failed)",
+        transport.to_string()
+    );
+    assert!(
+        !DNSimpleError::Transport(String::from("bad url"), TransportErrorKind::InvalidUrl)
+            .is_retryable()
+    );
+    assert!(!DNSimpleError::Transport(
+        String::from("no interaction recorded for GET /v2/whoami"),
+        TransportErrorKind::Cassette,
+    )
+    .is_retryable());
+    assert_eq!(None, not_found.retry_after());
 }
+
 #[test]
-fn transport() {
-    let setup = setup_mock_for("/other", "badgateway", "GET");
-    let client = setup.0;
+fn waits_for_the_rate_limit_reset_after_too_many_requests() {
+    let reset = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 60;
+    let mut server = Server::new();
+    server
+        .mock("GET", "/v2/whoami")
+        .with_status(429)
+        .with_header("X-RateLimit-Limit", "2400")
+        .with_header("X-RateLimit-Remaining", "0")
+        .with_header("X-RateLimit-Reset", &reset.to_string())
+        .with_body("{\"message\":\"API rate limit exceeded\"}")
+        .create();
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
 
-    let response = client.identity().whoami();
-    let error = response.unwrap_err();
+    let error = client.identity().whoami().unwrap_err();
 
-    assert_eq!("Transport Error - 501(Not Implemented)", error.to_string());
+    assert!(error.is_retryable());
+    assert!(!error.is_client_error());
+    assert_eq!(0, error.context().unwrap().rate_limit.unwrap().remaining);
+    assert_matches!(error.retry_after(), Some(wait) if wait > Duration::from_secs(50) && wait <= Duration::from_secs(60));
 }
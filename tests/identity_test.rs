@@ -1,28 +1,62 @@
-use crate::common::setup_mock_for;
+use crate::common::{client_tests, setup_mock_for};
+use dnsimple::dnsimple::new_client;
+use mockito::Server;
+use std::time::{Duration, UNIX_EPOCH};
 
 mod common;
 
-#[test]
-fn whoami_success_with_account() {
-    let setup = setup_mock_for("/whoami", "whoami/success-account", "GET");
-    let client = setup.0;
-    let identity = client.identity().whoami().unwrap();
+client_tests! {
+    fn whoami_success_with_account() {
+        let setup = setup_mock_for("/whoami", "whoami/success-account", "GET");
+        let client = setup.0;
+        let identity = client.identity().whoami().unwrap();
+
+        let account = identity.data.unwrap().account.unwrap();
+
+        assert_eq!(1, account.id);
+        assert_eq!("example-account@example.com", account.email);
+        assert_eq!("dnsimple-professional", account.plan_identifier);
+    }
+
+    fn whoami_success_with_user() {
+        let setup = setup_mock_for("/whoami", "whoami/success-user", "GET");
+        let client = setup.0;
+        let identity_response = client.identity().whoami().unwrap().data.unwrap();
+
+        let user = identity_response.user.unwrap();
+
+        assert_eq!(1, user.id);
+        assert_eq!("example-user@example.com", user.email);
+    }
+
+    fn whoami_returns_the_rate_limit() {
+        let setup = setup_mock_for("/whoami", "whoami/success-account", "GET");
+        let client = setup.0;
 
-    let account = identity.data.unwrap().account.unwrap();
+        let rate_limit = client.identity().whoami().unwrap().rate_limit.unwrap();
 
-    assert_eq!(1, account.id);
-    assert_eq!("example-account@example.com", account.email);
-    assert_eq!("dnsimple-professional", account.plan_identifier);
+        assert_eq!(2, rate_limit.limit);
+        assert_eq!(2, rate_limit.remaining);
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(1450451976),
+            rate_limit.reset
+        );
+        assert_eq!(Duration::ZERO, rate_limit.time_until_reset());
+    }
 }
 
 #[test]
-fn whoami_success_with_user() {
-    let setup = setup_mock_for("/whoami", "whoami/success-user", "GET");
-    let client = setup.0;
-    let identity_response = client.identity().whoami().unwrap().data.unwrap();
+fn whoami_tolerates_missing_rate_limit_headers() {
+    let mut server = Server::new();
+    server
+        .mock("GET", "/v2/whoami")
+        .with_body("{\"data\":{\"account\":null,\"user\":null}}")
+        .create();
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
 
-    let user = identity_response.user.unwrap();
+    let response = client.identity().whoami().unwrap();
 
-    assert_eq!(1, user.id);
-    assert_eq!("example-user@example.com", user.email);
+    assert_eq!(None, response.rate_limit);
+    assert_eq!(200, response.status);
 }
@@ -0,0 +1,58 @@
+use crate::common::{client_tests, setup_mock_for};
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::new_client;
+use dnsimple::errors::DNSimpleError;
+use mockito::Matcher;
+use serde_json::json;
+mod common;
+
+client_tests! {
+    fn test_normalizes_the_zone_name() {
+        let setup = setup_mock_for(
+            "/1010/zones/xn--bcher-kva.example",
+            "getZone/success",
+            "GET",
+        );
+        let client = setup.0;
+
+        let response = client.zones().get_zone(1010, "Bücher.Example.").unwrap();
+
+        assert_eq!(200, response.status);
+    }
+}
+
+#[test]
+fn test_normalizes_the_created_domain() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("POST", "/v2/1385/domains")
+        .match_body(Matcher::Json(json!({ "name": "xn--bcher-kva.example" })))
+        .with_status(201)
+        .with_body(common::read_fixture("createDomain/created").1)
+        .create();
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
+
+    client
+        .domains()
+        .create_domain(1385, String::from("BÜCHER.example"))
+        .unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn test_rejects_an_invalid_domain_without_sending() {
+    let mut server = mockito::Server::new();
+    let mock = server.mock("GET", Matcher::Any).expect(0).create();
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
+
+    let error = client
+        .registrar()
+        .check_domain(1010, "example.com/../../whoami")
+        .unwrap_err();
+
+    mock.assert();
+    assert_matches!(error, DNSimpleError::InvalidDomain { name, .. } if name == "example.com/../../whoami");
+}
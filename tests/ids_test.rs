@@ -0,0 +1,53 @@
+use crate::common::{client_tests, setup_mock_for};
+use dnsimple::dnsimple::{AccountId, DomainRef, ZoneRecordId};
+mod common;
+
+client_tests! {
+    fn test_domain_by_name() {
+        let setup = setup_mock_for(
+            "/1385/domains/example-alpha.com",
+            "getDomain/success",
+            "GET",
+        );
+        let client = setup.0;
+
+        let domain = client
+            .domains()
+            .get_domain(AccountId(1385), "example-alpha.com")
+            .unwrap()
+            .data
+            .unwrap();
+
+        assert_eq!(181984, domain.id);
+    }
+
+    fn test_domain_ref() {
+        let setup = setup_mock_for("/1385/domains/181984", "getDomain/success", "GET");
+        let client = setup.0;
+        let domain = DomainRef::Id(181984);
+
+        let response = client.domains().get_domain(1385, &domain).unwrap();
+
+        assert_eq!(200, response.status);
+        assert_eq!("181984", domain.to_string());
+    }
+
+    fn test_ids_of_the_models() {
+        let setup = setup_mock_for(
+            "/1010/zones/example.com/records/5",
+            "getZoneRecord/success",
+            "GET",
+        );
+        let client = setup.0;
+        let record_id = ZoneRecordId(5);
+
+        let record = client
+            .zones()
+            .get_zone_record(1010, "example.com", record_id)
+            .unwrap()
+            .data
+            .unwrap();
+
+        assert_eq!(record_id, record.id);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::common::{client_tests, setup_mock_for};
+use dnsimple::dnsimple::domains::{DomainListOptions, DomainSort};
+use dnsimple::dnsimple::templates::{TemplateListOptions, TemplateSort};
+use dnsimple::dnsimple::zones_records::{ZoneRecordListOptions, ZoneRecordSort};
+use dnsimple::dnsimple::{Client, Paginate, RequestOptions};
+use mockito::{Matcher, Server};
+mod common;
+
+#[test]
+fn sends_the_typed_zone_record_options() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/v2/1010/zones/example.com/records")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("name".into(), "".into()),
+            Matcher::UrlEncoded("name_like".into(), "www".into()),
+            Matcher::UrlEncoded("type".into(), "A".into()),
+            Matcher::UrlEncoded("sort".into(), "ttl:desc".into()),
+            Matcher::UrlEncoded("page".into(), "2".into()),
+            Matcher::UrlEncoded("per_page".into(), "10".into()),
+        ]))
+        .with_header("X-RateLimit-Limit", "2")
+        .with_header("X-RateLimit-Remaining", "2")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body("{\"data\":[]}")
+        .create();
+    let client = Client::builder(String::from("some-token"))
+        .base_url(&server.url())
+        .build()
+        .unwrap();
+    let options = ZoneRecordListOptions {
+        name: Some(String::new()),
+        name_like: Some(String::from("www")),
+        record_type: Some(String::from("A")),
+        sort: Some(ZoneRecordSort::TtlDesc),
+        paginate: Some(Paginate {
+            per_page: 10,
+            page: 2,
+        }),
+    };
+
+    client
+        .zones()
+        .list_zone_records(1010, "example.com", Some(options.into()))
+        .unwrap();
+
+    mock.assert();
+}
+
+client_tests! {
+    fn sends_the_typed_domain_options() {
+        let (client, _server) = setup_mock_for(
+            "/1385/domains?registrant_id=42&sort=expiration%3Aasc",
+            "listDomains/success",
+            "GET",
+        );
+        let options = DomainListOptions {
+            registrant_id: Some(42),
+            sort: Some(DomainSort::ExpirationAsc),
+            ..Default::default()
+        };
+
+        let domains = client
+            .domains()
+            .list_domains(1385, Some(options.into()))
+            .unwrap()
+            .data
+            .unwrap();
+
+        assert_eq!(2, domains.len());
+    }
+}
+
+#[test]
+fn leaves_out_the_options_not_set() {
+    let options: RequestOptions = TemplateListOptions {
+        sort: Some(TemplateSort::SidDesc),
+        paginate: None,
+    }
+    .into();
+
+    assert!(options.filters.is_none());
+    assert!(options.paginate.is_none());
+    assert_eq!("sid:desc", options.sort.unwrap().sort_by);
+    assert!(RequestOptions::from(DomainListOptions::default())
+        .filters
+        .is_none());
+}
+
+client_tests! {
+    fn sends_the_filters_in_a_stable_order() {
+        let (client, _server) = setup_mock_for(
+            "/1010/zones/example.com/records?name=&name_like=www&type=A",
+            "listZoneRecords/success",
+            "GET",
+        );
+
+        for _ in 0..20 {
+            let options = ZoneRecordListOptions {
+                name: Some(String::new()),
+                name_like: Some(String::from("www")),
+                record_type: Some(String::from("A")),
+                ..Default::default()
+            };
+
+            client
+                .zones()
+                .list_zone_records(1010, "example.com", Some(options.into()))
+                .unwrap();
+        }
+    }
+}
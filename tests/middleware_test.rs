@@ -0,0 +1,159 @@
+use crate::common::setup_mock_for;
+use dnsimple::dnsimple::{Client, HttpRequest, HttpResponse, Middleware, Next};
+use dnsimple::errors::DNSimpleError;
+use mockito::Server;
+use std::sync::{Arc, Mutex};
+
+mod common;
+
+struct Header(&'static str, &'static str);
+
+impl Middleware for Header {
+    fn handle(
+        &self,
+        mut request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, DNSimpleError> {
+        request
+            .headers
+            .push((String::from(self.0), String::from(self.1)));
+        next.run(request)
+    }
+}
+
+/// Records when it is called and what comes back from the rest of the chain.
+struct Recorder {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Recorder {
+    fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, DNSimpleError> {
+        self.log.lock().unwrap().push(format!(
+            "{} > {} {}",
+            self.name, request.method, request.url
+        ));
+
+        let result = next.run(request);
+
+        let outcome = match result {
+            Ok(ref response) => response.status.to_string(),
+            Err(ref error) => error.to_string(),
+        };
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} < {}", self.name, outcome));
+
+        result
+    }
+}
+
+struct Canned;
+
+impl Middleware for Canned {
+    fn handle(&self, _: HttpRequest, _: Next<'_>) -> Result<HttpResponse, DNSimpleError> {
+        Ok(HttpResponse {
+            status: 200,
+            status_text: String::from("OK"),
+            headers: Vec::new(),
+            body: String::from("{\"data\":{\"account\":null,\"user\":null}}"),
+        })
+    }
+}
+
+#[test]
+fn can_inject_headers() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/v2/whoami")
+        .match_header("X-Correlation-Id", "nightly-sync")
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3991")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body("{\"data\":{\"account\":null,\"user\":null}}")
+        .create();
+    let client = Client::builder(String::from("some-token"))
+        .base_url(&server.url())
+        .middleware(Header("X-Correlation-Id", "nightly-sync"))
+        .build()
+        .unwrap();
+
+    client.identity().whoami().unwrap();
+
+    mock.assert();
+}
+
+#[test]
+fn runs_the_middlewares_in_order() {
+    let (mut client, server) = setup_mock_for("/whoami", "whoami/success-account", "GET");
+    let log = Arc::new(Mutex::new(Vec::new()));
+    client.add_middleware(Recorder {
+        name: "first",
+        log: log.clone(),
+    });
+    client.add_middleware(Recorder {
+        name: "second",
+        log: log.clone(),
+    });
+
+    client.identity().whoami().unwrap();
+
+    let url = format!("{}/v2/whoami", server.url());
+    assert_eq!(
+        vec![
+            format!("first > GET {}", url),
+            format!("second > GET {}", url),
+            String::from("second < 200"),
+            String::from("first < 200"),
+        ],
+        *log.lock().unwrap()
+    );
+}
+
+#[test]
+fn sees_the_error_responses() {
+    let (mut client, _server) = setup_mock_for("/whoami", "notfound-certificate", "GET");
+    let log = Arc::new(Mutex::new(Vec::new()));
+    client.add_middleware(Recorder {
+        name: "recorder",
+        log: log.clone(),
+    });
+
+    let error = client.identity().whoami().unwrap_err();
+
+    assert_eq!("Certificate `0` not found", error.to_string());
+    assert_eq!("recorder < 404", log.lock().unwrap()[1]);
+}
+
+#[test]
+fn sees_the_transport_errors() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::builder(String::from("some-token"))
+        .base_url("http://127.0.0.1:1")
+        .middleware(Recorder {
+            name: "recorder",
+            log: log.clone(),
+        })
+        .build()
+        .unwrap();
+
+    let error = client.identity().whoami().unwrap_err();
+
+    assert!(matches!(error, DNSimpleError::Transport(..)));
+    assert_eq!(format!("recorder < {}", error), log.lock().unwrap()[1]);
+}
+
+#[test]
+fn can_answer_without_sending_the_request() {
+    let client = Client::builder(String::from("some-token"))
+        .base_url("http://127.0.0.1:1")
+        .middleware(Canned)
+        .build()
+        .unwrap();
+
+    let response = client.identity().whoami().unwrap();
+
+    assert_eq!(200, response.status);
+    assert_eq!(None, response.rate_limit);
+}
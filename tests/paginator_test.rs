@@ -0,0 +1,161 @@
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::{new_client, Client, Paginate, RequestOptions, Sort};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Matcher, Mock, ServerGuard};
+
+fn client_for(server: &ServerGuard) -> Client {
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
+    client
+}
+
+fn mock_page(
+    server: &mut ServerGuard,
+    page: u64,
+    per_page: u64,
+    ids: &[u64],
+    total_pages: u64,
+) -> Mock {
+    let webhooks: Vec<String> = ids
+        .iter()
+        .map(|id| format!("{{\"id\":{},\"url\":\"https://example.com/{}\"}}", id, id))
+        .collect();
+    let body = format!(
+        "{{\"data\":[{}],\"pagination\":{{\"current_page\":{},\"per_page\":{},\"total_entries\":5,\"total_pages\":{}}}}}",
+        webhooks.join(","),
+        page,
+        per_page,
+        total_pages
+    );
+
+    server
+        .mock("GET", "/v2/1010/webhooks")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("page".into(), page.to_string()),
+            Matcher::UrlEncoded("per_page".into(), per_page.to_string()),
+        ]))
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3991")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body(body)
+        .create()
+}
+
+fn two_per_page() -> Option<RequestOptions> {
+    Some(RequestOptions {
+        paginate: Some(Paginate {
+            per_page: 2,
+            page: 1,
+        }),
+        ..RequestOptions::default()
+    })
+}
+
+#[test]
+fn iterates_over_all_the_pages() {
+    let mut server = mockito::Server::new();
+    let pages = [
+        mock_page(&mut server, 1, 2, &[1, 2], 3),
+        mock_page(&mut server, 2, 2, &[3, 4], 3),
+        mock_page(&mut server, 3, 2, &[5], 3),
+    ];
+    let client = client_for(&server);
+
+    let webhooks = client
+        .webhooks()
+        .list_webhooks_iter(1010, two_per_page())
+        .collect_all()
+        .unwrap();
+
+    let ids: Vec<u64> = webhooks.iter().map(|webhook| webhook.id).collect();
+    assert_eq!(vec![1, 2, 3, 4, 5], ids);
+    for page in pages {
+        page.assert();
+    }
+}
+
+#[test]
+fn fetches_the_pages_lazily() {
+    let mut server = mockito::Server::new();
+    let first = mock_page(&mut server, 1, 2, &[1, 2], 3);
+    let second = mock_page(&mut server, 2, 2, &[3, 4], 3).expect(0);
+    let client = client_for(&server);
+
+    let webhooks: Vec<_> = client
+        .webhooks()
+        .list_webhooks_iter(1010, two_per_page())
+        .take(2)
+        .collect();
+
+    assert_eq!(2, webhooks.len());
+    first.assert();
+    second.assert();
+}
+
+#[test]
+fn requests_the_largest_pages_by_default() {
+    let mut server = mockito::Server::new();
+    let page = mock_page(&mut server, 1, 100, &[1, 2], 1);
+    let client = client_for(&server);
+
+    let webhooks = client
+        .webhooks()
+        .list_webhooks_iter(1010, None)
+        .collect_all()
+        .unwrap();
+
+    assert_eq!(2, webhooks.len());
+    page.assert();
+}
+
+#[test]
+fn sends_the_sorting_with_every_page() {
+    let mut server = mockito::Server::new();
+    let mock = server
+        .mock("GET", "/v2/1010/zones/example.com/records")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("sort".into(), "name:asc".into()),
+            Matcher::UrlEncoded("page".into(), "1".into()),
+        ]))
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3991")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body("{\"data\":[],\"pagination\":{\"current_page\":1,\"per_page\":100,\"total_entries\":0,\"total_pages\":1}}")
+        .create();
+    let client = client_for(&server);
+    let options = RequestOptions {
+        sort: Some(Sort::new(String::from("name:asc"))),
+        ..RequestOptions::default()
+    };
+
+    let records = client
+        .zones()
+        .list_zone_records_iter(1010, "example.com", Some(options))
+        .collect_all()
+        .unwrap();
+
+    assert!(records.is_empty());
+    mock.assert();
+}
+
+#[test]
+fn stops_at_the_first_error() {
+    let mut server = mockito::Server::new();
+    mock_page(&mut server, 1, 2, &[1, 2], 3);
+    server
+        .mock("GET", "/v2/1010/webhooks")
+        .match_query(Matcher::UrlEncoded("page".into(), "2".into()))
+        .with_status(503)
+        .create();
+    let client = client_for(&server);
+
+    let mut webhooks = client.webhooks().list_webhooks_iter(1010, two_per_page());
+
+    assert_eq!(1, webhooks.next().unwrap().unwrap().id);
+    assert_eq!(2, webhooks.next().unwrap().unwrap().id);
+    assert_matches!(
+        webhooks.next(),
+        Some(Err(DNSimpleError::ServiceUnavailable { .. }))
+    );
+    assert!(webhooks.next().is_none());
+}
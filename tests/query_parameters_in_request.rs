@@ -1,5 +1,5 @@
 use crate::common::setup_mock_for;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 mod common;
 use dnsimple::dnsimple::{Endpoint, Filters, Paginate, RequestOptions, Sort};
 use serde::Deserialize;
@@ -38,7 +38,7 @@ fn can_paginate() {
 fn can_filter() {
     let setup = setup_mock_for("/filter_test?name_like=example", "pages-2of3", "GET");
     let client = setup.0;
-    let mut filters = HashMap::new();
+    let mut filters = BTreeMap::new();
     filters.insert("name_like".to_string(), "example".to_string());
     let options = RequestOptions {
         filters: Some(Filters { filters }),
@@ -0,0 +1,77 @@
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::{Client, RateLimitMode, RateLimiter};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Mock, Server, ServerGuard};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+fn client_for(server: &ServerGuard, limiter: RateLimiter) -> Client {
+    Client::builder(String::from("some-token"))
+        .base_url(&server.url())
+        .rate_limiter(limiter)
+        .build()
+        .unwrap()
+}
+
+fn mock_whoami(server: &mut ServerGuard, remaining: &str, reset_in: u64, hits: usize) -> Mock {
+    let reset = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + reset_in;
+
+    server
+        .mock("GET", "/v2/whoami")
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", remaining)
+        .with_header("X-RateLimit-Reset", &reset.to_string())
+        .with_body("{\"data\":{}}")
+        .expect(hits)
+        .create()
+}
+
+#[test]
+fn fails_before_going_below_the_floor() {
+    let mut server = Server::new();
+    let mock = mock_whoami(&mut server, "10", 3600, 1);
+    let limiter = RateLimiter::new(10, RateLimitMode::Fail);
+    let client = client_for(&server, limiter.clone());
+
+    client.identity().whoami().unwrap();
+    let error = client.identity().whoami().unwrap_err();
+
+    assert_matches!(
+        error,
+        DNSimpleError::RateLimitWouldExceed { remaining: 10, .. }
+    );
+    assert_eq!(Some(10), limiter.remaining());
+    mock.assert();
+}
+
+#[test]
+fn blocks_until_the_rate_limit_resets() {
+    let mut server = Server::new();
+    let mock = mock_whoami(&mut server, "0", 2, 2);
+    let client = client_for(&server, RateLimiter::new(0, RateLimitMode::Block));
+
+    client.identity().whoami().unwrap();
+    let started = Instant::now();
+    client.identity().whoami().unwrap();
+
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    mock.assert();
+}
+
+#[test]
+fn shares_the_rate_limit_between_clients() {
+    let mut server = Server::new();
+    let mock = mock_whoami(&mut server, "5", 3600, 1);
+    let limiter = RateLimiter::new(5, RateLimitMode::Fail);
+    let client = client_for(&server, limiter.clone());
+    let other_client = client_for(&server, limiter);
+
+    client.identity().whoami().unwrap();
+    let error = other_client.identity().whoami().unwrap_err();
+
+    assert_matches!(error, DNSimpleError::RateLimitWouldExceed { .. });
+    mock.assert();
+}
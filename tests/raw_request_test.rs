@@ -0,0 +1,98 @@
+use crate::common::setup_mock_for;
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::zones::Zone;
+use dnsimple::dnsimple::{new_client, Method, Paginate, RequestOptions};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Matcher, Server};
+use serde::Deserialize;
+use serde_json::{json, Value};
+mod common;
+
+#[test]
+fn sends_a_typed_get_request_test() {
+    let setup = setup_mock_for("/1010/zones", "listZones/success", "GET");
+    let client = setup.0;
+
+    let response = client
+        .request::<Vec<Zone>>(Method::Get, "/1010/zones")
+        .send()
+        .unwrap();
+
+    assert_eq!(200, response.status);
+    assert_eq!(2, response.rate_limit.unwrap().limit);
+    assert_eq!(1, response.pagination.unwrap().current_page);
+    assert_eq!("example-alpha.com", response.data.unwrap()[0].name);
+}
+
+#[test]
+fn sends_a_post_request_with_query_and_payload_test() {
+    #[derive(Deserialize)]
+    struct Snapshot {
+        id: u64,
+    }
+
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/v2/1010/zones/example.com/snapshots")
+        .match_header("Authorization", "Bearer some-token")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("notify".into(), "true".into()),
+            Matcher::UrlEncoded("page".into(), "2".into()),
+        ]))
+        .match_body(Matcher::Json(json!({ "label": "before-migration" })))
+        .with_status(201)
+        .with_body("{\"data\":{\"id\":42}}")
+        .create();
+    let mut client = new_client(true, String::from("some-token"));
+    client.set_base_url(&server.url());
+
+    let response = client
+        .request::<Snapshot>(Method::Post, "/1010/zones/example.com/snapshots")
+        .query("notify", true)
+        .options(RequestOptions {
+            paginate: Some(Paginate {
+                per_page: 30,
+                page: 2,
+            }),
+            ..RequestOptions::default()
+        })
+        .json(json!({ "label": "before-migration" }))
+        .send()
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(201, response.status);
+    assert_eq!(42, response.data.unwrap().id);
+}
+
+#[test]
+fn reads_an_empty_response_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/records/5",
+        "deleteZoneRecord/success",
+        "DELETE",
+    );
+    let client = setup.0;
+
+    let response = client
+        .request::<Value>(Method::Delete, "/1010/zones/example.com/records/5")
+        .send()
+        .unwrap();
+
+    assert_eq!(204, response.status);
+    assert!(response.data.is_none());
+    assert!(response.body.is_none());
+}
+
+#[test]
+fn fails_like_the_services_test() {
+    let setup = setup_mock_for("/1010/zones/0", "notfound-zone", "GET");
+    let client = setup.0;
+
+    let error = client
+        .request::<Zone>(Method::Get, "/1010/zones/0")
+        .send()
+        .unwrap_err();
+
+    assert_matches!(error, DNSimpleError::NotFound { message, .. } if message == "Zone `0` not found");
+}
@@ -0,0 +1,113 @@
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::{Client, RetryPolicy};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Server, ServerGuard};
+use std::time::Duration;
+
+fn client_for(server: &ServerGuard, policy: RetryPolicy) -> Client {
+    Client::builder(String::from("some-token"))
+        .base_url(&server.url())
+        .retry_policy(policy)
+        .build()
+        .unwrap()
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(10),
+        retry_non_idempotent: false,
+    }
+}
+
+#[test]
+fn retries_idempotent_requests_until_max_attempts() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/v2/whoami")
+        .with_status(503)
+        .expect(3)
+        .create();
+    let client = client_for(&server, policy());
+
+    let error = client.identity().whoami().unwrap_err();
+
+    assert_matches!(error, DNSimpleError::ServiceUnavailable { .. });
+    mock.assert();
+}
+
+#[test]
+fn retries_too_many_requests_once_the_rate_limit_resets() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("DELETE", "/v2/1010/zones/example.com/records/2")
+        .with_status(429)
+        .with_header("X-RateLimit-Reset", "0")
+        .expect(3)
+        .create();
+    let client = client_for(&server, policy());
+
+    let error = client
+        .zones()
+        .delete_zone_record(1010, "example.com", 2)
+        .unwrap_err();
+
+    assert_matches!(error, DNSimpleError::TooManyRequests { .. });
+    mock.assert();
+}
+
+#[test]
+fn does_not_retry_post_requests_by_default() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/v2/1010/domains/pushes/42")
+        .with_status(502)
+        .expect(1)
+        .create();
+    let client = client_for(&server, policy());
+
+    let error = client.domains().accept_push(1010, 42).unwrap_err();
+
+    assert_matches!(error, DNSimpleError::BadGateway { .. });
+    mock.assert();
+}
+
+#[test]
+fn retries_post_requests_when_enabled() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/v2/1010/domains/pushes/42")
+        .with_status(502)
+        .expect(3)
+        .create();
+    let client = client_for(
+        &server,
+        RetryPolicy {
+            retry_non_idempotent: true,
+            ..policy()
+        },
+    );
+
+    let error = client.domains().accept_push(1010, 42).unwrap_err();
+
+    assert_matches!(error, DNSimpleError::BadGateway { .. });
+    mock.assert();
+}
+
+#[test]
+fn does_not_retry_by_default() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/v2/whoami")
+        .with_status(503)
+        .expect(1)
+        .create();
+    let mut client = client_for(&server, policy());
+    client.set_retry_policy(RetryPolicy::none());
+
+    let error = client.identity().whoami().unwrap_err();
+
+    assert_matches!(error, DNSimpleError::ServiceUnavailable { .. });
+    mock.assert();
+}
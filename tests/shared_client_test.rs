@@ -0,0 +1,89 @@
+use dnsimple::dnsimple::zones::Zones;
+use dnsimple::dnsimple::{Client, DryRun, RateLimitMode, RateLimiter};
+use mockito::Server;
+use std::thread;
+
+struct ZoneSync {
+    zones: Zones,
+    account_id: u64,
+}
+
+#[test]
+fn moves_service_handles_into_threads_test() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("GET", "/v2/1010/zones/example.com")
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3990")
+        .with_header("X-RateLimit-Reset", "4102444800")
+        .with_body("{\"data\":{\"id\":1,\"account_id\":1010,\"name\":\"example.com\",\"reverse\":false,\"created_at\":\"2015-04-23T07:40:03Z\",\"updated_at\":\"2015-04-23T07:40:03Z\"}}")
+        .expect(4)
+        .create();
+    let limiter = RateLimiter::new(10, RateLimitMode::Fail);
+    let client = Client::builder(String::from("some-token"))
+        .base_url(&server.url())
+        .rate_limiter(limiter.clone())
+        .build()
+        .unwrap();
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let sync = ZoneSync {
+                zones: client.zones(),
+                account_id: 1010,
+            };
+            thread::spawn(move || {
+                sync.zones
+                    .get_zone(sync.account_id, "example.com")
+                    .unwrap()
+                    .data
+                    .unwrap()
+                    .name
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        assert_eq!("example.com", worker.join().unwrap());
+    }
+    mock.assert();
+    assert_eq!(Some(3990), limiter.remaining());
+}
+
+#[test]
+fn clones_share_the_dry_run_test() {
+    let plan = DryRun::new();
+    let client = Client::builder(String::from("some-token"))
+        .dry_run(plan.clone())
+        .build()
+        .unwrap();
+    let clone = client.clone();
+
+    thread::spawn(move || clone.zones().delete_zone_record(1010, "example.com", 5))
+        .join()
+        .unwrap()
+        .unwrap();
+    client
+        .zones()
+        .delete_zone_record(1010, "example.com", 6)
+        .unwrap();
+
+    assert_eq!(2, plan.operations().len());
+}
+
+#[test]
+fn changing_a_clone_leaves_the_client_alone_test() {
+    let client = Client::builder(String::from("some-token"))
+        .sandbox(true)
+        .build()
+        .unwrap();
+    let mut clone = client.clone();
+
+    clone.set_base_url("https://example.com");
+
+    assert_eq!(
+        "https://api.sandbox.dnsimple.com/v2",
+        client.versioned_url()
+    );
+    assert_eq!("https://example.com/v2", clone.versioned_url());
+}
@@ -0,0 +1,309 @@
+#![cfg(feature = "testing")]
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::contacts::ContactPayload;
+use dnsimple::dnsimple::templates::{TemplatePayload, TemplateRecordPayload};
+use dnsimple::dnsimple::zones_records::{ZoneRecordPayload, ZoneRecordUpdatePayload};
+use dnsimple::dnsimple::{Client, Paginate, RequestOptions};
+use dnsimple::errors::DNSimpleError;
+use dnsimple::testing::FakeServer;
+use serde_json::Value;
+use std::fs;
+use std::net::TcpStream;
+use std::time::Duration;
+
+const ACCOUNT: u64 = FakeServer::ACCOUNT_ID;
+
+fn record_payload(name: &str, record_type: &str) -> ZoneRecordPayload {
+    ZoneRecordPayload {
+        name: String::from(name),
+        record_type: String::from(record_type),
+        content: String::from("127.0.0.1"),
+        ttl: None,
+        priority: None,
+        regions: None,
+    }
+}
+
+#[test]
+fn keeps_the_domains_zones_and_records() {
+    let server = FakeServer::start().unwrap();
+    let client = server.client();
+
+    let domain = client
+        .domains()
+        .create_domain(ACCOUNT, String::from("example.com"))
+        .unwrap()
+        .data
+        .unwrap();
+    let zone = client.zones().get_zone(ACCOUNT, "example.com").unwrap();
+    let record = client
+        .zones()
+        .create_zone_record(ACCOUNT, "example.com", record_payload("www", "A"))
+        .unwrap()
+        .data
+        .unwrap();
+    let updated = client
+        .zones()
+        .update_zone_record(
+            ACCOUNT,
+            "example.com",
+            record.id,
+            ZoneRecordUpdatePayload {
+                name: None,
+                content: Some(String::from("10.0.0.1")),
+                ttl: Some(60),
+                priority: None,
+                regions: None,
+            },
+        )
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!("example.com", domain.name);
+    assert_eq!("hosted", domain.state);
+    assert_eq!("example.com", zone.data.unwrap().name);
+    assert_eq!("example.com", record.zone_id);
+    assert_eq!(3600, record.ttl);
+    assert_eq!("www", updated.name);
+    assert_eq!("10.0.0.1", updated.content);
+    assert_eq!(60, updated.ttl);
+
+    let records = client
+        .zones()
+        .list_zone_records(ACCOUNT, "example.com", None)
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(
+        vec![record.id],
+        records.iter().map(|r| r.id).collect::<Vec<_>>()
+    );
+
+    client
+        .zones()
+        .delete_zone_record(ACCOUNT, "example.com", record.id)
+        .unwrap();
+    assert!(client
+        .zones()
+        .list_zone_records(ACCOUNT, "example.com", None)
+        .unwrap()
+        .data
+        .unwrap()
+        .is_empty());
+
+    client.domains().delete_domain(ACCOUNT, domain.id).unwrap();
+    assert_matches!(
+        client.zones().get_zone(ACCOUNT, "example.com"),
+        Err(DNSimpleError::NotFound { message, .. }) if message == "Zone `example.com` not found"
+    );
+}
+
+#[test]
+fn mirrors_the_validation_errors_of_the_api() {
+    let server = FakeServer::start().unwrap();
+    let payload = ContactPayload {
+        label: None,
+        first_name: String::new(),
+        last_name: String::new(),
+        job_title: None,
+        organization_name: None,
+        email: String::new(),
+        phone: String::new(),
+        fax: None,
+        address1: String::new(),
+        address2: None,
+        city: String::new(),
+        state_province: String::new(),
+        postal_code: String::new(),
+        country: String::new(),
+    };
+
+    let error = server
+        .client()
+        .contacts()
+        .create_contact(ACCOUNT, payload)
+        .unwrap_err();
+
+    let fixture = fs::read_to_string("./tests/fixtures/v2/api/validation-error.http").unwrap();
+    let expected: Value = serde_json::from_str(fixture.lines().last().unwrap()).unwrap();
+    assert_matches!(error, DNSimpleError::BadRequest { message, attribute_errors, .. } => {
+        assert_eq!("Validation failed", message);
+        assert_eq!(Some(expected["errors"].clone()), attribute_errors);
+    });
+
+    let error = server
+        .client()
+        .zones()
+        .create_zone_record(ACCOUNT, "example.com", record_payload("www", "A"))
+        .unwrap_err();
+    assert_matches!(error, DNSimpleError::NotFound { .. });
+}
+
+#[test]
+fn keeps_the_contacts_templates_and_webhooks() {
+    let server = FakeServer::start().unwrap();
+    let client = server.client();
+
+    let template = client
+        .templates()
+        .create_template(
+            ACCOUNT,
+            TemplatePayload {
+                name: String::from("Beta"),
+                sid: String::from("beta"),
+                description: None,
+            },
+        )
+        .unwrap()
+        .data
+        .unwrap();
+    let template_record = client
+        .templates()
+        .create_template_record(
+            ACCOUNT,
+            String::from("beta"),
+            TemplateRecordPayload {
+                name: String::new(),
+                record_type: String::from("MX"),
+                content: String::from("mx.example.com"),
+                ttl: Some(600),
+                priority: Some(10),
+            },
+        )
+        .unwrap()
+        .data
+        .unwrap();
+    let webhook = client
+        .webhooks()
+        .create_webhook(ACCOUNT, String::from("https://example.com/webhooks"))
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(template.id, template_record.template_id);
+    assert_eq!(Some(10), template_record.priority);
+    assert_eq!(
+        1,
+        client
+            .templates()
+            .list_template_records(ACCOUNT, template.id.to_string(), None)
+            .unwrap()
+            .data
+            .unwrap()
+            .len()
+    );
+    assert_eq!(
+        "https://example.com/webhooks",
+        client
+            .webhooks()
+            .get_webhook(ACCOUNT, webhook.id)
+            .unwrap()
+            .data
+            .unwrap()
+            .url
+    );
+
+    client
+        .templates()
+        .delete_template(ACCOUNT, String::from("beta"))
+        .unwrap();
+    client
+        .webhooks()
+        .delete_webhook(ACCOUNT, webhook.id)
+        .unwrap();
+
+    assert!(client
+        .templates()
+        .list_templates(ACCOUNT, None)
+        .unwrap()
+        .data
+        .unwrap()
+        .is_empty());
+    assert_matches!(
+        client.webhooks().get_webhook(ACCOUNT, webhook.id),
+        Err(DNSimpleError::NotFound { .. })
+    );
+}
+
+#[test]
+fn paginates_the_lists() {
+    let server = FakeServer::start().unwrap();
+    let client = server.client();
+    client
+        .domains()
+        .create_domain(ACCOUNT, String::from("example.com"))
+        .unwrap();
+    for name in ["a", "b", "c", "d", "e"] {
+        client
+            .zones()
+            .create_zone_record(ACCOUNT, "example.com", record_payload(name, "A"))
+            .unwrap();
+    }
+
+    let page = client
+        .zones()
+        .list_zone_records(
+            ACCOUNT,
+            "example.com",
+            Some(RequestOptions {
+                filters: None,
+                sort: None,
+                paginate: Some(Paginate {
+                    per_page: 2,
+                    page: 3,
+                }),
+            }),
+        )
+        .unwrap();
+    let pagination = page.pagination.unwrap();
+
+    assert_eq!(1, page.data.unwrap().len());
+    assert_eq!(5, pagination.total_entries);
+    assert_eq!(3, pagination.total_pages);
+    assert_eq!(
+        5,
+        client
+            .zones()
+            .list_zone_records_iter(ACCOUNT, "example.com", None)
+            .collect_all()
+            .unwrap()
+            .len()
+    );
+}
+
+#[test]
+fn knows_the_accounts() {
+    let server = FakeServer::start().unwrap();
+    let client = server.client();
+    let other = server.add_account("jane@example.com");
+
+    let whoami = client.identity().whoami().unwrap().data.unwrap();
+    let accounts = client.accounts().list_accounts().unwrap().data.unwrap();
+
+    assert_eq!(ACCOUNT, whoami.account.unwrap().id);
+    assert_eq!(
+        vec![ACCOUNT, other],
+        accounts.iter().map(|a| a.id).collect::<Vec<_>>()
+    );
+    assert_matches!(
+        client.webhooks().list_webhooks(42, None),
+        Err(DNSimpleError::NotFound { .. })
+    );
+}
+
+#[test]
+fn serves_the_connections_concurrently() {
+    let server = FakeServer::start().unwrap();
+    let idle = TcpStream::connect(server.url().trim_start_matches("http://")).unwrap();
+    let client = Client::builder(String::from("fake-token"))
+        .base_url(&server.url())
+        .read_timeout(Duration::from_secs(1))
+        .build()
+        .unwrap();
+
+    let whoami = client.identity().whoami().unwrap().data.unwrap();
+
+    assert_eq!(ACCOUNT, whoami.account.unwrap().id);
+    drop(idle);
+}
@@ -0,0 +1,68 @@
+#![cfg(any(feature = "chrono", feature = "time"))]
+use crate::common::{client_tests, setup_mock_for};
+mod common;
+
+client_tests! {
+    #[cfg(feature = "chrono")]
+    fn test_chrono_timestamps() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let setup = setup_mock_for("/1385/domains/181984", "getDomain/success", "GET");
+        let client = setup.0;
+
+        let domain = client
+            .domains()
+            .get_domain(1385, 181984)
+            .unwrap()
+            .data
+            .unwrap();
+
+        assert_eq!(NaiveDate::from_ymd_opt(2021, 6, 5), domain.expires_on);
+        assert_eq!(
+            Utc.with_ymd_and_hms(2021, 6, 5, 2, 15, 0).single(),
+            domain.expires_at
+        );
+        assert_eq!(
+            Utc.with_ymd_and_hms(2020, 6, 4, 19, 15, 14).single(),
+            Some(domain.created_at)
+        );
+        assert!(domain.created_at < domain.updated_at);
+    }
+}
+
+client_tests! {
+    #[cfg(feature = "time")]
+    fn test_time_timestamps() {
+        use time::{Date, Month, Time};
+
+        let setup = setup_mock_for("/1385/domains/181984", "getDomain/success", "GET");
+        let client = setup.0;
+
+        let domain = client
+            .domains()
+            .get_domain(1385, 181984)
+            .unwrap()
+            .data
+            .unwrap();
+
+        let expires_on = Date::from_calendar_date(2021, Month::June, 5).unwrap();
+        let created_on = Date::from_calendar_date(2020, Month::June, 4).unwrap();
+
+        assert_eq!(Some(expires_on), domain.expires_on);
+        assert_eq!(
+            Some(
+                expires_on
+                    .with_time(Time::from_hms(2, 15, 0).unwrap())
+                    .assume_utc()
+            ),
+            domain.expires_at
+        );
+        assert_eq!(
+            created_on
+                .with_time(Time::from_hms(19, 15, 14).unwrap())
+                .assume_utc(),
+            domain.created_at
+        );
+        assert!(domain.created_at < domain.updated_at);
+    }
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "tracing")]
+
+use crate::common::setup_mock_for;
+use std::io;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::fmt::MakeWriter;
+
+mod common;
+
+/// Collects the output of the subscriber.
+#[derive(Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl Output {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Output {
+    type Writer = Output;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+fn traced(call: impl FnOnce()) -> String {
+    let output = Output::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(output.clone())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .finish();
+
+    tracing::subscriber::with_default(subscriber, call);
+
+    output.contents()
+}
+
+#[test]
+fn traces_the_calls() {
+    let (client, _server) = setup_mock_for(
+        "/1010/zones/example.com/records/5",
+        "getZoneRecord/success",
+        "GET",
+    );
+
+    let output = traced(|| {
+        client
+            .zones()
+            .get_zone_record(1010, "example.com", 5)
+            .unwrap();
+    });
+
+    assert!(output.contains("dnsimple.request"));
+    assert!(output.contains("http.method=\"GET\""));
+    assert!(output.contains("http.path=\"/{account}/zones/{zone}/records/{record}\""));
+    assert!(output.contains("http.status=200"));
+    assert!(output.contains("rate_limit.remaining=2"));
+    assert!(output.contains("latency_ms="));
+}
+
+#[test]
+fn traces_the_errors() {
+    let (client, _server) = setup_mock_for("/whoami", "notfound-certificate", "GET");
+
+    let output = traced(|| {
+        client.identity().whoami().unwrap_err();
+    });
+
+    assert!(output.contains("WARN"));
+    assert!(output.contains("DNSimple API call failed"));
+    assert!(output.contains("Certificate `0` not found"));
+    assert!(output.contains("http.status=404"));
+}
+
+#[test]
+fn never_traces_the_token() {
+    let (client, _server) = setup_mock_for("/whoami", "whoami/success-account", "GET");
+
+    let output = traced(|| {
+        client.identity().whoami().unwrap();
+    });
+
+    assert!(!output.is_empty());
+    assert!(!output.contains("some-token"));
+    assert!(!output.contains("Bearer"));
+}
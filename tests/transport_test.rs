@@ -0,0 +1,235 @@
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::zones_records::ZoneRecordPayload;
+use dnsimple::dnsimple::{Client, HttpRequest, HttpResponse, Paginate, RequestOptions, Transport};
+use dnsimple::errors::{DNSimpleError, ErrorContext, TransportErrorKind};
+use serde_json::json;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Answers every request with the same fixture (or fails to connect without one),
+/// remembering the requests it was given.
+#[derive(Clone)]
+struct FakeTransport {
+    response: Option<HttpResponse>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl FakeTransport {
+    fn with_fixture(fixture: &str) -> FakeTransport {
+        let content =
+            fs::read_to_string(format!("./tests/fixtures/v2/api/{}.http", fixture)).unwrap();
+
+        FakeTransport {
+            response: Some(HttpResponse {
+                status: content[9..12].parse().unwrap(),
+                status_text: content[13..content.find('\n').unwrap()].trim().to_string(),
+                headers: vec![
+                    (String::from("X-RateLimit-Limit"), String::from("4000")),
+                    (String::from("X-RateLimit-Remaining"), String::from("3991")),
+                    (
+                        String::from("X-RateLimit-Reset"),
+                        String::from("1450451976"),
+                    ),
+                ],
+                body: content.lines().last().unwrap().to_string(),
+            }),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Transport for FakeTransport {
+    fn send(&self, request: &HttpRequest) -> Result<HttpResponse, DNSimpleError> {
+        self.requests.lock().unwrap().push(request.clone());
+        self.response.clone().ok_or_else(|| {
+            DNSimpleError::Transport(
+                String::from("connection refused"),
+                TransportErrorKind::ConnectionFailed,
+            )
+        })
+    }
+}
+
+fn client_with(transport: &FakeTransport) -> Client {
+    Client::builder(String::from("some-token"))
+        .base_url("https://api.example.com")
+        .transport(transport.clone())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn sends_the_requests_through_the_transport() {
+    let transport = FakeTransport::with_fixture("whoami/success-account");
+    let client = client_with(&transport);
+
+    let response = client.identity().whoami().unwrap();
+
+    assert_eq!(1, response.data.unwrap().account.unwrap().id);
+    assert_eq!(3991, response.rate_limit.unwrap().remaining);
+
+    let requests = transport.requests();
+    assert_eq!(1, requests.len());
+    assert_eq!("GET", requests[0].method);
+    assert_eq!("https://api.example.com/v2/whoami", requests[0].url);
+    assert_eq!(
+        Some("Bearer some-token"),
+        requests[0].header("authorization")
+    );
+    assert_eq!(Some("application/json"), requests[0].header("Accept"));
+}
+
+#[test]
+fn describes_the_query_parameters_and_payload() {
+    let transport = FakeTransport::with_fixture("createZoneRecord/created");
+    let client = client_with(&transport);
+    let payload = ZoneRecordPayload {
+        name: String::from("www"),
+        record_type: String::from("A"),
+        content: String::from("127.0.0.1"),
+        ttl: None,
+        priority: None,
+        regions: None,
+    };
+
+    client
+        .zones()
+        .create_zone_record(1010, "example.com", payload)
+        .unwrap();
+    client
+        .zones()
+        .list_zones(
+            1010,
+            Some(RequestOptions {
+                paginate: Some(Paginate {
+                    per_page: 5,
+                    page: 2,
+                }),
+                ..RequestOptions::default()
+            }),
+        )
+        .ok();
+
+    let requests = transport.requests();
+    assert_eq!("POST", requests[0].method);
+    assert_eq!(
+        Some(
+            json!({"name": "www", "type": "A", "content": "127.0.0.1", "ttl": null, "priority": null, "regions": null})
+        ),
+        requests[0].body
+    );
+    assert_eq!(
+        vec![
+            (String::from("page"), String::from("2")),
+            (String::from("per_page"), String::from("5")),
+        ],
+        requests[1].query
+    );
+}
+
+#[test]
+fn maps_error_statuses_to_errors() {
+    let transport = FakeTransport::with_fixture("notfound-certificate");
+    let client = client_with(&transport);
+
+    let error = client.identity().whoami().unwrap_err();
+
+    assert_matches!(error, DNSimpleError::NotFound { message, context } => {
+        assert_eq!("Certificate `0` not found", message);
+        assert_eq!(404, context.status);
+        assert_eq!("GET", context.method);
+        assert_eq!("/v2/whoami", context.path);
+    });
+}
+
+#[test]
+fn returns_the_transport_errors() {
+    let transport = FakeTransport {
+        response: None,
+        requests: Arc::new(Mutex::new(Vec::new())),
+    };
+    let client = client_with(&transport);
+
+    let error = client.identity().whoami().unwrap_err();
+
+    assert_matches!(error, DNSimpleError::Transport(_, kind) => {
+        assert_eq!(TransportErrorKind::ConnectionFailed, kind);
+    });
+}
+
+#[test]
+fn classifies_the_connection_failures() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+    let client = Client::builder(String::from("some-token"))
+        .base_url(&url)
+        .build()
+        .unwrap();
+
+    let error = client.identity().whoami().unwrap_err();
+
+    assert!(error.is_retryable());
+    assert_matches!(error, DNSimpleError::Transport(_, kind) => {
+        assert_eq!(TransportErrorKind::ConnectionFailed, kind);
+    });
+}
+
+#[test]
+fn fails_when_the_response_body_cannot_be_read() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut buffer = [0; 4096];
+        let _ = stream.read(&mut buffer).unwrap();
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n{\"data\":")
+            .unwrap();
+    });
+    let client = Client::builder(String::from("some-token"))
+        .base_url(&url)
+        .build()
+        .unwrap();
+
+    let error = client.identity().whoami().unwrap_err();
+    server.join().unwrap();
+
+    assert_matches!(error, DNSimpleError::Transport(message, kind) => {
+        assert!(message.starts_with("cannot read the response body"));
+        assert_eq!(TransportErrorKind::Io, kind);
+    });
+}
+
+#[test]
+fn parses_error_responses_without_ureq() {
+    let response = HttpResponse {
+        status: 402,
+        status_text: String::from("Payment Required"),
+        headers: Vec::new(),
+        body: String::new(),
+    };
+
+    let request = HttpRequest::new("DELETE", "https://api.dnsimple.com/v2/1010/webhooks/1");
+
+    assert_eq!(
+        DNSimpleError::PaymentRequired {
+            context: Box::new(ErrorContext {
+                status: 402,
+                request_id: None,
+                method: String::from("DELETE"),
+                path: String::from("/v2/1010/webhooks/1"),
+                body: String::new(),
+                rate_limit: None,
+            })
+        },
+        DNSimpleError::parse_response(&request, &response)
+    );
+}